@@ -0,0 +1,48 @@
+use std::hint::black_box;
+
+use cookie_rs::jar::split_cookie_header;
+use cookie_rs::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const HEADER: &str = "_ga=GA1.2.1234567890.1761031680; _gid=GA1.2.987654321.1761031680; \
+    theme=dark; lang=en-US; consent=%7B%22ads%22%3Afalse%7D; csrftoken=Zm9vYmFyYmF6; \
+    session=abc123def456; tracking=off";
+
+fn find_session(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find session");
+
+    group.bench_function("CookieJar::parse", |b| {
+        b.iter(|| {
+            let jar = CookieJar::parse(black_box(HEADER)).unwrap();
+            jar.get("session").map(|c| c.value().len())
+        })
+    });
+
+    group.bench_function("CookieJar::find_in_header", |b| {
+        b.iter(|| CookieJar::find_in_header(black_box(HEADER), "session").map(str::len))
+    });
+
+    group.finish();
+}
+
+fn all_pairs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("all pairs");
+
+    group.bench_function("CookieJar::parse", |b| {
+        b.iter(|| {
+            CookieJar::parse(black_box(HEADER))
+                .unwrap()
+                .cookie()
+                .count()
+        })
+    });
+
+    group.bench_function("split_cookie_header", |b| {
+        b.iter(|| split_cookie_header(black_box(HEADER)).count())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, find_session, all_pairs);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A string attribute of a `Cookie`.
+///
+/// Parsing an owned string moves it into a shared buffer, and the attributes
+/// of the resulting cookies are byte ranges into it. Every range keeps its
+/// own handle to the buffer, so cookies can be cloned, moved and taken out of
+/// a `CookieJar` without copying and without outliving the text they point to.
+#[derive(Debug, Clone)]
+pub(crate) enum CookieStr<'a> {
+    Cow(Cow<'a, str>),
+    Shared(Arc<str>, Range<usize>),
+}
+
+impl<'a> CookieStr<'a> {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CookieStr::Cow(cow) => cow,
+            CookieStr::Shared(buffer, range) => &buffer[range.clone()],
+        }
+    }
+
+    /// Converts into a `'static` string, copying borrowed text.
+    pub fn into_owned(self) -> CookieStr<'static> {
+        match self {
+            CookieStr::Cow(cow) => CookieStr::Cow(Cow::Owned(cow.into_owned())),
+            CookieStr::Shared(buffer, range) => CookieStr::Shared(buffer, range),
+        }
+    }
+
+    /// Converts text borrowed from `buffer` into a range of it.
+    ///
+    /// Anything else, such as a percent-decoded value, is copied.
+    pub fn share(self, buffer: &Arc<str>) -> CookieStr<'static> {
+        let CookieStr::Cow(Cow::Borrowed(str)) = self else {
+            return self.into_owned();
+        };
+
+        // only the addresses are compared, the buffer is never accessed through them
+        let start = (str.as_ptr() as usize).wrapping_sub(buffer.as_ptr() as usize);
+
+        match start.checked_add(str.len()) {
+            Some(end) if end <= buffer.len() => CookieStr::Shared(Arc::clone(buffer), start..end),
+            _ => CookieStr::Cow(Cow::Owned(str.to_owned())),
+        }
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for CookieStr<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Self::Cow(value)
+    }
+}
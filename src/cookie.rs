@@ -1,9 +1,14 @@
-use std::borrow::{Borrow, Cow};
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt;
 use std::time::Duration;
 
+#[cfg(feature = "actix")]
+pub use self::actix::ActixCookieError;
 pub use self::builder::CookieBuilder;
-use crate::StringPrison;
+pub use self::expiration::Expiration;
+use self::validate::ValidationError;
+use crate::CookieStr;
 
 #[cfg(feature = "percent-encoding")]
 const COOKIE_VALUE_ENCODE_SET: percent_encoding::AsciiSet = percent_encoding::CONTROLS
@@ -15,31 +20,58 @@ const COOKIE_VALUE_ENCODE_SET: percent_encoding::AsciiSet = percent_encoding::CO
     .add(b'\\');
 
 pub mod builder;
+pub mod date;
+pub mod expiration;
 pub mod parse;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub mod prefix;
+pub mod validate;
+
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "serde")]
+mod serde;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum SameSite {
     Strict,
     Lax,
     None,
 }
 
+/// The `Priority` attribute, which Chromium uses to pick the cookies to evict
+/// first when a domain exceeds its cookie limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
 /// Represents an HTTP cookie, including attributes such as domain, path, and expiration.
 #[derive(Debug, Clone)]
 pub struct Cookie<'a> {
-    prison: Option<StringPrison<'a>>,
-    name: Cow<'a, str>,
-    value: Cow<'a, str>,
-    domain: Option<Cow<'a, str>>,
-    expires: Option<Cow<'a, str>>,
+    name: CookieStr<'a>,
+    value: CookieStr<'a>,
+    domain: Option<CookieStr<'a>>,
+    expires: Option<Expiration>,
+    expires_raw: Option<CookieStr<'a>>,
     http_only: Option<bool>,
     max_age: Option<Duration>,
     partitioned: Option<bool>,
-    path: Option<Cow<'a, str>>,
+    path: Option<CookieStr<'a>>,
+    priority: Option<Priority>,
     same_site: Option<SameSite>,
     secure: Option<bool>,
+    extensions: Vec<Extension<'a>>,
 }
 
+/// An unrecognised attribute, as a name and an optional value.
+type Extension<'a> = (CookieStr<'a>, Option<CookieStr<'a>>);
+
 impl<'a> Cookie<'a> {
     /// Creates a new `Cookie` with the specified name and value.
     ///
@@ -61,12 +93,39 @@ impl<'a> Cookie<'a> {
         V: Into<Cow<'a, str>>,
     {
         Self {
-            name: name.into(),
-            value: value.into(),
+            name: CookieStr::Cow(name.into()),
+            value: CookieStr::Cow(value.into()),
             ..Default::default()
         }
     }
 
+    /// Creates a new `Cookie` with the specified name and value, checking that
+    /// both are valid.
+    ///
+    /// See [`Cookie::validate`].
+    ///
+    /// # Arguments
+    /// - `name`: The name of the cookie.
+    /// - `value`: The value of the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// assert!(Cookie::try_new("session", "abc123").is_ok());
+    /// assert!(Cookie::try_new("session;", "abc123").is_err());
+    /// ```
+    pub fn try_new<N, V>(name: N, value: V) -> Result<Self, ValidationError>
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        let cookie = Self::new(name, value);
+        cookie.validate()?;
+
+        Ok(cookie)
+    }
+
     /// Creates a `CookieBuilder` for constructing a `Cookie` with additional attributes.
     ///
     /// # Arguments
@@ -90,6 +149,23 @@ impl<'a> Cookie<'a> {
         CookieBuilder::new(name, value)
     }
 
+    /// Sets the value of the cookie.
+    ///
+    /// # Arguments
+    /// - `value`: The value of the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let mut cookie = Cookie::new("session", "abc123");
+    /// cookie.set_value("def456");
+    /// assert_eq!(cookie.value(), "def456");
+    /// ```
+    pub fn set_value<V: Into<Cow<'a, str>>>(&mut self, value: V) {
+        self.value = CookieStr::Cow(value.into());
+    }
+
     /// Sets the domain for the cookie.
     ///
     /// # Arguments
@@ -104,11 +180,13 @@ impl<'a> Cookie<'a> {
     /// assert_eq!(cookie.domain(), Some("example.com"));
     /// ```
     pub fn set_domain<V: Into<Cow<'a, str>>>(&mut self, domain: V) {
-        self.domain = Some(domain.into())
+        self.domain = Some(CookieStr::Cow(domain.into()))
     }
 
     /// Sets the expiration date for the cookie.
     ///
+    /// Any raw `Expires` text kept from parsing is discarded.
+    ///
     /// # Arguments
     /// - `expires`: The expiration date of the cookie.
     ///
@@ -117,11 +195,12 @@ impl<'a> Cookie<'a> {
     /// use cookie_rs::prelude::*;
     ///
     /// let mut cookie = Cookie::new("session", "abc123");
-    /// cookie.set_expires("Wed, 21 Oct 2025 07:28:00 GMT");
-    /// assert_eq!(cookie.expires(), Some("Wed, 21 Oct 2025 07:28:00 GMT"));
+    /// cookie.set_expires(Expiration::from_unix_timestamp(1761031680));
+    /// assert_eq!(cookie.expires(), Some(Expiration::from_unix_timestamp(1761031680)));
     /// ```
-    pub fn set_expires<V: Into<Cow<'a, str>>>(&mut self, expires: V) {
+    pub fn set_expires<V: Into<Expiration>>(&mut self, expires: V) {
         self.expires = Some(expires.into());
+        self.expires_raw = None;
     }
 
     /// Sets the `HttpOnly` attribute for the cookie.
@@ -190,7 +269,24 @@ impl<'a> Cookie<'a> {
     /// assert_eq!(cookie.path(), Some("/"));
     /// ```
     pub fn set_path<V: Into<Cow<'a, str>>>(&mut self, path: V) {
-        self.path = Some(path.into());
+        self.path = Some(CookieStr::Cow(path.into()));
+    }
+
+    /// Sets the `Priority` attribute for the cookie.
+    ///
+    /// # Arguments
+    /// - `priority`: The `Priority` attribute for the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let mut cookie = Cookie::new("session", "abc123");
+    /// cookie.set_priority(Priority::High);
+    /// assert_eq!(cookie.priority(), Some(Priority::High));
+    /// ```
+    pub fn set_priority(&mut self, priority: Priority) {
+        self.priority = Some(priority);
     }
 
     /// Sets the `SameSite` attribute for the cookie.
@@ -227,6 +323,99 @@ impl<'a> Cookie<'a> {
         self.secure = Some(secure);
     }
 
+    /// Sets an extension attribute of the form `name=value`.
+    ///
+    /// An existing extension with the same name, compared case-insensitively,
+    /// is replaced in place. Otherwise the extension is appended, and written
+    /// after all known attributes.
+    ///
+    /// A known attribute name, such as `Priority` or `Path`, sets that
+    /// attribute instead, the way [`Cookie::parse`] reads it, so it is never
+    /// written twice. A value that is invalid for the attribute is ignored.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the attribute.
+    /// - `value`: The value of the attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let mut cookie = Cookie::new("session", "abc123");
+    /// cookie.set_extension("Vendor", "1");
+    /// assert_eq!(cookie.to_string(), "session=abc123; Vendor=1");
+    ///
+    /// cookie.set_extension("priority", "High");
+    /// assert_eq!(cookie.priority(), Some(Priority::High));
+    /// assert_eq!(cookie.extensions().count(), 1);
+    /// ```
+    pub fn set_extension<N, V>(&mut self, name: N, value: V)
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        let (name, value) = (name.into(), value.into());
+
+        if !self.set_known_attribute(&name, Some(value.clone())) {
+            self.insert_extension(name, Some(CookieStr::Cow(value)));
+        }
+    }
+
+    /// Sets an extension attribute that is a bare flag, like `Secure`.
+    ///
+    /// An existing extension with the same name, compared case-insensitively,
+    /// is replaced in place. Known attribute names are handled like in
+    /// [`Cookie::set_extension`].
+    ///
+    /// # Arguments
+    /// - `name`: The name of the attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let mut cookie = Cookie::new("session", "abc123");
+    /// cookie.set_extension_flag("SameParty");
+    /// assert_eq!(cookie.to_string(), "session=abc123; SameParty");
+    /// ```
+    pub fn set_extension_flag<N: Into<Cow<'a, str>>>(&mut self, name: N) {
+        let name = name.into();
+
+        if !self.set_known_attribute(&name, None) {
+            self.insert_extension(name, None);
+        }
+    }
+
+    /// Removes the extension attributes named `name`, compared case-insensitively.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let mut cookie = Cookie::parse("session=abc123; SameParty").unwrap();
+    /// cookie.remove_extension("sameparty");
+    /// assert_eq!(cookie.extensions().count(), 0);
+    /// ```
+    pub fn remove_extension(&mut self, name: &str) {
+        self.extensions
+            .retain(|(n, _)| !n.as_str().eq_ignore_ascii_case(name));
+    }
+
+    fn insert_extension(&mut self, name: Cow<'a, str>, value: Option<CookieStr<'a>>) {
+        let existing = self
+            .extensions
+            .iter_mut()
+            .find(|(n, _)| n.as_str().eq_ignore_ascii_case(&name));
+
+        match existing {
+            Some(extension) => *extension = (CookieStr::Cow(name), value),
+            None => self.extensions.push((CookieStr::Cow(name), value)),
+        }
+    }
+
     /// Sets the domain for the cookie.
     ///
     /// # Arguments
@@ -255,11 +444,12 @@ impl<'a> Cookie<'a> {
     /// ```
     /// use cookie_rs::prelude::*;
     ///
-    /// let cookie = Cookie::new("session", "abc123").with_expires("Wed, 21 Oct 2025 07:28:00 GMT");
+    /// let cookie = Cookie::new("session", "abc123")
+    ///     .with_expires(Expiration::from_unix_timestamp(1761031680));
     ///
-    /// assert_eq!(cookie.expires(), Some("Wed, 21 Oct 2025 07:28:00 GMT"));
+    /// assert_eq!(cookie.expires(), Some(Expiration::from_unix_timestamp(1761031680)));
     /// ```
-    pub fn with_expires<V: Into<Cow<'a, str>>>(mut self, expires: V) -> Self {
+    pub fn with_expires<V: Into<Expiration>>(mut self, expires: V) -> Self {
         self.set_expires(expires);
 
         self
@@ -361,6 +551,24 @@ impl<'a> Cookie<'a> {
         self
     }
 
+    /// Sets the `Priority` attribute for the cookie.
+    ///
+    /// # Arguments
+    /// - `priority`: The `Priority` attribute for the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::new("session", "abc123").with_priority(Priority::High);
+    /// assert_eq!(cookie.priority(), Some(Priority::High));
+    /// ```
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.set_priority(priority);
+
+        self
+    }
+
     /// Sets the `SameSite` attribute for the cookie.
     ///
     /// # Arguments
@@ -390,7 +598,7 @@ impl<'a> Cookie<'a> {
     /// assert_eq!(cookie.name(), "session");
     /// ```
     pub fn name(&self) -> &str {
-        self.name.as_ref()
+        self.name.as_str()
     }
 
     /// Returns the value of the cookie.
@@ -403,7 +611,7 @@ impl<'a> Cookie<'a> {
     /// assert_eq!(cookie.value(), "abc123");
     /// ```
     pub fn value(&self) -> &str {
-        self.value.as_ref()
+        self.value.as_str()
     }
 
     /// Returns the domain of the cookie, if set.
@@ -417,7 +625,7 @@ impl<'a> Cookie<'a> {
     /// assert_eq!(cookie.domain(), Some("example.com"));
     /// ```
     pub fn domain(&self) -> Option<&str> {
-        self.domain.as_deref()
+        self.domain.as_ref().map(CookieStr::as_str)
     }
 
     /// Returns the expiration date of the cookie, if set.
@@ -427,11 +635,25 @@ impl<'a> Cookie<'a> {
     /// use cookie_rs::prelude::*;
     ///
     /// let mut cookie = Cookie::new("session", "abc123");
-    /// cookie.set_expires("Wed, 21 Oct 2025 07:28:00 GMT");
-    /// assert_eq!(cookie.expires(), Some("Wed, 21 Oct 2025 07:28:00 GMT"));
+    /// cookie.set_expires(Expiration::from_unix_timestamp(1761031680));
+    /// assert_eq!(cookie.expires(), Some(Expiration::from_unix_timestamp(1761031680)));
+    /// ```
+    pub fn expires(&self) -> Option<Expiration> {
+        self.expires
+    }
+
+    /// Returns the raw `Expires` attribute text as it appeared in the parsed string, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::parse("session=abc123; Expires=wed, 21 oct 2025 07:28:00 GMT").unwrap();
+    /// assert_eq!(cookie.expires_raw(), Some("wed, 21 oct 2025 07:28:00 GMT"));
+    /// assert_eq!(cookie.expires(), Some(Expiration::from_unix_timestamp(1761031680)));
     /// ```
-    pub fn expires(&self) -> Option<&str> {
-        self.expires.as_deref()
+    pub fn expires_raw(&self) -> Option<&str> {
+        self.expires_raw.as_ref().map(CookieStr::as_str)
     }
 
     /// Returns whether the cookie has the `HttpOnly` attribute set.
@@ -488,7 +710,21 @@ impl<'a> Cookie<'a> {
     /// assert_eq!(cookie.path(), Some("/"));
     /// ```
     pub fn path(&self) -> Option<&str> {
-        self.path.as_deref()
+        self.path.as_ref().map(CookieStr::as_str)
+    }
+
+    /// Returns the `Priority` attribute of the cookie, if set.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let mut cookie = Cookie::new("session", "abc123");
+    /// cookie.set_priority(Priority::Low);
+    /// assert_eq!(cookie.priority(), Some(Priority::Low));
+    /// ```
+    pub fn priority(&self) -> Option<Priority> {
+        self.priority
     }
 
     /// Returns the `SameSite` attribute of the cookie, if set.
@@ -519,6 +755,28 @@ impl<'a> Cookie<'a> {
         self.secure
     }
 
+    /// Returns the extension attributes of the cookie in order.
+    ///
+    /// Attributes not recognised while parsing in lenient mode are kept here,
+    /// with `None` as the value of a bare flag.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::parse("session=abc123; SameParty; Vendor=1").unwrap();
+    ///
+    /// assert_eq!(
+    ///     cookie.extensions().collect::<Vec<_>>(),
+    ///     [("SameParty", None), ("Vendor", Some("1"))]
+    /// );
+    /// ```
+    pub fn extensions(&self) -> impl Iterator<Item = (&str, Option<&str>)> + '_ {
+        self.extensions
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_ref().map(CookieStr::as_str)))
+    }
+
     /// Converts the cookie into an owned version with a `'static` lifetime.
     ///
     /// # Example
@@ -533,63 +791,101 @@ impl<'a> Cookie<'a> {
     /// ```
     pub fn into_owned(self) -> Cookie<'static> {
         Cookie {
-            prison: None,
-            name: Cow::Owned(self.name.into_owned()),
-            value: Cow::Owned(self.value.into_owned()),
-            domain: self.domain.map(|v| Cow::Owned(v.into_owned())),
-            expires: self.expires.map(|v| Cow::Owned(v.into_owned())),
+            name: self.name.into_owned(),
+            value: self.value.into_owned(),
+            domain: self.domain.map(CookieStr::into_owned),
+            expires: self.expires,
+            expires_raw: self.expires_raw.map(CookieStr::into_owned),
             http_only: self.http_only,
             max_age: self.max_age,
             partitioned: self.partitioned,
-            path: self.path.map(|v| Cow::Owned(v.into_owned())),
+            path: self.path.map(CookieStr::into_owned),
+            priority: self.priority,
             same_site: self.same_site,
             secure: self.secure,
+            extensions: self
+                .extensions
+                .into_iter()
+                .map(|(name, value)| (name.into_owned(), value.map(CookieStr::into_owned)))
+                .collect(),
         }
     }
 }
 
 impl PartialEq for Cookie<'_> {
     fn eq(&self, other: &Self) -> bool {
-        match (self.domain.as_ref(), other.domain.as_ref()) {
-            (Some(a), Some(b)) if a.eq_ignore_ascii_case(b) => (),
-            (None, None) => (),
-            _ => return false,
-        }
-
-        match (self.path.as_ref(), other.path.as_ref()) {
-            (Some(a), Some(b)) if a.eq_ignore_ascii_case(b) => (),
-            (None, None) => (),
-            _ => return false,
-        }
-
-        self.name == other.name
-            && self.value == other.value
-            && self.expires == other.expires
-            && self.http_only == other.http_only
-            && self.max_age == other.max_age
-            && self.partitioned == other.partitioned
-            && self.same_site == other.same_site
-            && self.secure == other.secure
+        self.cmp(other).is_eq()
     }
 }
 
 impl Eq for Cookie<'_> {}
 
 impl PartialOrd for Cookie<'_> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
+/// Cookies are ordered by name, `Domain` and `Path` first, the way a
+/// [`CookieJar`](crate::CookieJar) identifies them, then by their remaining
+/// attributes.
 impl Ord for Cookie<'_> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.name.cmp(&other.name)
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_identity(other)
+            .then_with(|| self.value().cmp(other.value()))
+            .then_with(|| self.expires.cmp(&other.expires))
+            .then_with(|| self.http_only.cmp(&other.http_only))
+            .then_with(|| self.max_age.cmp(&other.max_age))
+            .then_with(|| self.partitioned.cmp(&other.partitioned))
+            .then_with(|| self.priority.cmp(&other.priority))
+            .then_with(|| self.same_site.cmp(&other.same_site))
+            .then_with(|| self.secure.cmp(&other.secure))
+            .then_with(|| self.extensions().cmp(other.extensions()))
     }
 }
 
-impl Borrow<str> for Cookie<'_> {
-    fn borrow(&self) -> &str {
+impl Cookie<'_> {
+    /// Compares the name, `Domain` and `Path` of two cookies. Domains are
+    /// compared case-insensitively, ignoring a leading dot.
+    pub(crate) fn cmp_identity(&self, other: &Self) -> Ordering {
         self.name()
+            .cmp(other.name())
+            .then_with(|| cmp_domain(self.domain(), other.domain()))
+            .then_with(|| self.path().cmp(&other.path()))
+    }
+
+    /// Returns the value as it appears in a header, percent-encoded when the
+    /// `percent-encoding` feature is enabled.
+    pub(crate) fn encoded_value(&self) -> Cow<'_, str> {
+        #[cfg(feature = "percent-encoding")]
+        return percent_encoding::utf8_percent_encode(self.value(), &COOKIE_VALUE_ENCODE_SET)
+            .into();
+        #[cfg(not(feature = "percent-encoding"))]
+        return Cow::Borrowed(self.value());
+    }
+}
+
+/// Decodes a value as it appears in a header, the same way [`Cookie::parse`]
+/// does. Returns `None` if the percent-decoded bytes are not valid UTF-8.
+pub(crate) fn decode_value(value: &str) -> Option<Cow<'_, str>> {
+    #[cfg(feature = "percent-encoding")]
+    return percent_encoding::percent_decode_str(value)
+        .decode_utf8()
+        .ok();
+    #[cfg(not(feature = "percent-encoding"))]
+    return Some(Cow::Borrowed(value));
+}
+
+fn cmp_domain(a: Option<&str>, b: Option<&str>) -> Ordering {
+    fn normalize(domain: &str) -> impl Iterator<Item = u8> + '_ {
+        let domain = domain.strip_prefix('.').unwrap_or(domain);
+
+        domain.bytes().map(|b| b.to_ascii_lowercase())
+    }
+
+    match (a, b) {
+        (Some(a), Some(b)) => normalize(a).cmp(normalize(b)),
+        (a, b) => a.is_some().cmp(&b.is_some()),
     }
 }
 
@@ -599,6 +895,24 @@ impl<'a> From<&'a str> for Cookie<'a> {
     }
 }
 
+impl<'a> From<&'a String> for Cookie<'a> {
+    fn from(value: &'a String) -> Self {
+        Cookie::new(value, "")
+    }
+}
+
+impl From<String> for Cookie<'_> {
+    fn from(value: String) -> Self {
+        Cookie::new(value, "")
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for Cookie<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Cookie::new(value, "")
+    }
+}
+
 impl<'a> From<(&'a str, &'a str)> for Cookie<'a> {
     fn from(value: (&'a str, &'a str)) -> Self {
         Cookie::new(value.0, value.1)
@@ -615,17 +929,9 @@ impl std::str::FromStr for Cookie<'_> {
 
 impl fmt::Display for Cookie<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        #[cfg(not(feature = "percent-encoding"))]
-        write!(f, "{}={}", self.name, self.value)?;
-        #[cfg(feature = "percent-encoding")]
-        write!(
-            f,
-            "{}={}",
-            self.name,
-            percent_encoding::utf8_percent_encode(&self.value, &COOKIE_VALUE_ENCODE_SET)
-        )?;
-
-        if let Some(domain) = self.domain.as_ref() {
+        write!(f, "{}={}", self.name(), self.encoded_value())?;
+
+        if let Some(domain) = self.domain() {
             write!(f, "; Domain={domain}")?;
         }
 
@@ -645,10 +951,14 @@ impl fmt::Display for Cookie<'_> {
             write!(f, "; Partitioned")?;
         }
 
-        if let Some(path) = self.path.as_ref() {
+        if let Some(path) = self.path() {
             write!(f, "; Path={path}")?;
         }
 
+        if let Some(priority) = self.priority {
+            write!(f, "; Priority={priority}")?;
+        }
+
         if let Some(same_site) = self.same_site {
             write!(f, "; SameSite={same_site}")?;
         }
@@ -657,6 +967,13 @@ impl fmt::Display for Cookie<'_> {
             write!(f, "; Secure")?;
         }
 
+        for (name, value) in self.extensions() {
+            match value {
+                Some(value) => write!(f, "; {name}={value}")?,
+                None => write!(f, "; {name}")?,
+            }
+        }
+
         Ok(())
     }
 }
@@ -671,20 +988,32 @@ impl fmt::Display for SameSite {
     }
 }
 
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Priority::Low => write!(f, "Low"),
+            Priority::Medium => write!(f, "Medium"),
+            Priority::High => write!(f, "High"),
+        }
+    }
+}
+
 impl Default for Cookie<'_> {
     fn default() -> Self {
         Self {
-            prison: None,
-            name: Cow::Borrowed(""),
-            value: Cow::Borrowed(""),
+            name: CookieStr::Cow(Cow::Borrowed("")),
+            value: CookieStr::Cow(Cow::Borrowed("")),
             domain: None,
             expires: None,
+            expires_raw: None,
             http_only: None,
             max_age: None,
             partitioned: None,
             path: None,
+            priority: None,
             same_site: None,
             secure: None,
+            extensions: Vec::new(),
         }
     }
 }
@@ -0,0 +1,184 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use actix_web::cookie::time::{self, OffsetDateTime};
+use actix_web::cookie::{self as actix, CookieBuilder as ActixCookieBuilder};
+
+use super::{decode_value, Cookie, Expiration, SameSite};
+
+/// An error returned when a [`Cookie`] has no equivalent `actix_web::cookie::Cookie`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActixCookieError {
+    /// The `Partitioned` attribute is not supported by actix-web's cookie type.
+    Partitioned,
+    /// The `Priority` attribute is not supported by actix-web's cookie type.
+    Priority,
+    /// Extension attributes are not supported by actix-web's cookie type. Holds
+    /// the name of the first one.
+    Extension(String),
+    /// The `Expires` date is outside of the range supported by actix-web.
+    ExpiresOutOfRange(Expiration),
+}
+
+impl TryFrom<&Cookie<'_>> for actix::Cookie<'static> {
+    type Error = ActixCookieError;
+
+    /// Converts a cookie into an actix-web cookie.
+    ///
+    /// Fails instead of dropping the `Partitioned` and `Priority` attributes or
+    /// extension attributes, which actix-web's cookie type cannot represent.
+    /// [`CookieJar::write_to_response`](crate::CookieJar::write_to_response)
+    /// writes the headers directly and keeps them. The value is percent-encoded
+    /// as in the `Set-Cookie` header, since actix-web holds it as raw header
+    /// text. A `Max-Age` beyond `i64::MAX` seconds is capped.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::builder("session", "abc123").same_site(SameSite::Lax).build();
+    /// let actix = actix_web::cookie::Cookie::try_from(&cookie).unwrap();
+    ///
+    /// assert_eq!(actix.same_site(), Some(actix_web::cookie::SameSite::Lax));
+    /// ```
+    fn try_from(value: &Cookie<'_>) -> Result<Self, Self::Error> {
+        if value.partitioned.is_some_and(|v| v) {
+            return Err(ActixCookieError::Partitioned);
+        }
+
+        if value.priority.is_some() {
+            return Err(ActixCookieError::Priority);
+        }
+
+        if let Some((name, _)) = value.extensions().next() {
+            return Err(ActixCookieError::Extension(name.to_owned()));
+        }
+
+        let mut builder =
+            ActixCookieBuilder::new(value.name().to_owned(), value.encoded_value().into_owned());
+
+        if let Some(domain) = value.domain() {
+            builder = builder.domain(domain.to_owned());
+        }
+
+        if let Some(expires) = value.expires() {
+            let datetime = OffsetDateTime::from_unix_timestamp(expires.unix_timestamp())
+                .map_err(|_| ActixCookieError::ExpiresOutOfRange(expires))?;
+
+            builder = builder.expires(datetime);
+        }
+
+        if let Some(max_age) = value.max_age() {
+            let secs = i64::try_from(max_age.as_secs()).unwrap_or(i64::MAX);
+
+            builder = builder.max_age(time::Duration::seconds(secs));
+        }
+
+        if let Some(path) = value.path() {
+            builder = builder.path(path.to_owned());
+        }
+
+        if let Some(same_site) = value.same_site() {
+            builder = builder.same_site(same_site.into());
+        }
+
+        let mut cookie = builder.finish();
+        cookie.set_http_only(value.http_only);
+        cookie.set_secure(value.secure);
+
+        Ok(cookie)
+    }
+}
+
+impl From<&actix::Cookie<'_>> for Cookie<'static> {
+    /// Converts an actix-web cookie into a cookie.
+    ///
+    /// The raw value is percent-decoded the same way [`Cookie::parse`] does,
+    /// and kept as it is if it does not decode to valid UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let actix = actix_web::cookie::Cookie::build("session", "abc123").path("/").finish();
+    /// let cookie = Cookie::from(&actix);
+    ///
+    /// assert_eq!(cookie.to_string(), "session=abc123; Path=/");
+    /// ```
+    fn from(value: &actix::Cookie<'_>) -> Self {
+        let raw = value.value();
+        let decoded = decode_value(raw).map_or_else(|| raw.to_owned(), |v| v.into_owned());
+        let mut cookie = Cookie::new(value.name().to_owned(), decoded);
+
+        cookie.expires = value
+            .expires_datetime()
+            .map(|v| Expiration::from_unix_timestamp(v.unix_timestamp()));
+        cookie.http_only = value.http_only();
+        cookie.max_age = value
+            .max_age()
+            .map(|v| Duration::from_secs(v.whole_seconds().max(0) as u64));
+        cookie.same_site = value.same_site().map(Into::into);
+        cookie.secure = value.secure();
+
+        if let Some(domain) = value.domain() {
+            cookie.set_domain(domain.to_owned());
+        }
+
+        if let Some(path) = value.path() {
+            cookie.set_path(path.to_owned());
+        }
+
+        cookie
+    }
+}
+
+impl From<SameSite> for actix::SameSite {
+    fn from(value: SameSite) -> Self {
+        match value {
+            SameSite::Strict => Self::Strict,
+            SameSite::Lax => Self::Lax,
+            SameSite::None => Self::None,
+        }
+    }
+}
+
+impl From<actix::SameSite> for SameSite {
+    fn from(value: actix::SameSite) -> Self {
+        match value {
+            actix::SameSite::Strict => Self::Strict,
+            actix::SameSite::Lax => Self::Lax,
+            actix::SameSite::None => Self::None,
+        }
+    }
+}
+
+impl Error for ActixCookieError {}
+
+impl fmt::Display for ActixCookieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActixCookieError::Partitioned => {
+                write!(
+                    f,
+                    "actix-web cookies do not support the Partitioned attribute."
+                )
+            }
+            ActixCookieError::Priority => {
+                write!(
+                    f,
+                    "actix-web cookies do not support the Priority attribute."
+                )
+            }
+            ActixCookieError::Extension(name) => {
+                write!(
+                    f,
+                    "actix-web cookies do not support extension attributes: {name}"
+                )
+            }
+            ActixCookieError::ExpiresOutOfRange(expires) => {
+                write!(f, "Expires out of range: {}", expires.unix_timestamp())
+            }
+        }
+    }
+}
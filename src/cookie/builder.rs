@@ -1,7 +1,11 @@
 use std::borrow::Cow;
 use std::time::Duration;
 
-use super::{Cookie, SameSite};
+use super::prefix::Prefix;
+#[cfg(not(feature = "percent-encoding"))]
+use super::validate::validate_value;
+use super::validate::{validate_name, ValidationError};
+use super::{Cookie, Expiration, Priority, SameSite};
 
 /// A builder for constructing `Cookie` instances with optional attributes.
 ///
@@ -30,6 +34,96 @@ impl<'a> CookieBuilder<'a> {
         Self(Cookie::new(name, value))
     }
 
+    /// Creates a new `CookieBuilder`, checking that the name and value are valid.
+    ///
+    /// The name must be a `token`, and the value is checked against
+    /// `cookie-octet` when the `percent-encoding` feature is disabled. The
+    /// requirements of a name prefix are only checked by
+    /// [`CookieBuilder::try_build`], once the attributes are set.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the cookie.
+    /// - `value`: The value of the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::error::ValidationError;
+    ///
+    /// let cookie = CookieBuilder::try_new("__Host-session", "abc123")
+    ///     .unwrap()
+    ///     .secure(true)
+    ///     .path("/")
+    ///     .try_build();
+    /// assert!(cookie.is_ok());
+    ///
+    /// let result = CookieBuilder::try_new("session id", "abc123");
+    /// assert!(matches!(result, Err(ValidationError::InvalidName(' '))));
+    /// ```
+    pub fn try_new<N, V>(name: N, value: V) -> Result<Self, ValidationError>
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        let builder = Self::new(name, value);
+        validate_name(builder.0.name())?;
+
+        #[cfg(not(feature = "percent-encoding"))]
+        validate_value(builder.0.value())?;
+
+        Ok(builder)
+    }
+
+    /// Creates a `CookieBuilder` for a `__Host-` prefixed cookie.
+    ///
+    /// The prefix is prepended to `name` unless already present, and the
+    /// `Secure` and `Path=/` attributes the prefix requires are set.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the cookie without the prefix.
+    /// - `value`: The value of the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = CookieBuilder::host_prefixed("session", "abc123").build();
+    /// assert_eq!(cookie.to_string(), "__Host-session=abc123; Path=/; Secure");
+    /// ```
+    pub fn host_prefixed<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        Self::new(prefixed(Prefix::Host, name.into()), value)
+            .secure(true)
+            .path("/")
+    }
+
+    /// Creates a `CookieBuilder` for a `__Secure-` prefixed cookie.
+    ///
+    /// The prefix is prepended to `name` unless already present, and the
+    /// `Secure` attribute the prefix requires is set.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the cookie without the prefix.
+    /// - `value`: The value of the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = CookieBuilder::secure_prefixed("id", "1").domain("example.com").build();
+    /// assert_eq!(cookie.to_string(), "__Secure-id=1; Domain=example.com; Secure");
+    /// ```
+    pub fn secure_prefixed<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        Self::new(prefixed(Prefix::Secure, name.into()), value).secure(true)
+    }
+
     /// Sets the domain for the cookie.
     ///
     /// # Arguments
@@ -60,11 +154,11 @@ impl<'a> CookieBuilder<'a> {
     /// use cookie_rs::prelude::*;
     ///
     /// let cookie = CookieBuilder::new("session", "abc123")
-    ///     .expires("Wed, 21 Oct 2025 07:28:00 GMT")
+    ///     .expires(Expiration::from_unix_timestamp(1761031680))
     ///     .build();
-    /// assert_eq!(cookie.expires(), Some("Wed, 21 Oct 2025 07:28:00 GMT"));
+    /// assert_eq!(cookie.expires(), Some(Expiration::from_unix_timestamp(1761031680)));
     /// ```
-    pub fn expires<V: Into<Cow<'a, str>>>(mut self, expires: V) -> Self {
+    pub fn expires<V: Into<Expiration>>(mut self, expires: V) -> Self {
         self.0.set_expires(expires);
 
         self
@@ -151,6 +245,26 @@ impl<'a> CookieBuilder<'a> {
         self
     }
 
+    /// Sets the `Priority` attribute for the cookie.
+    ///
+    /// # Arguments
+    /// - `priority`: The `Priority` attribute for the cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = CookieBuilder::new("session", "abc123")
+    ///     .priority(Priority::High)
+    ///     .build();
+    /// assert_eq!(cookie.priority(), Some(Priority::High));
+    /// ```
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.0.set_priority(priority);
+
+        self
+    }
+
     /// Sets the `SameSite` attribute for the cookie.
     ///
     /// # Arguments
@@ -191,6 +305,51 @@ impl<'a> CookieBuilder<'a> {
         self
     }
 
+    /// Sets an extension attribute of the form `name=value`.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the attribute.
+    /// - `value`: The value of the attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = CookieBuilder::new("session", "abc123")
+    ///     .extension("Vendor", "1")
+    ///     .build();
+    /// assert_eq!(cookie.extensions().next(), Some(("Vendor", Some("1"))));
+    /// ```
+    pub fn extension<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.0.set_extension(name, value);
+
+        self
+    }
+
+    /// Sets an extension attribute that is a bare flag.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = CookieBuilder::new("session", "abc123")
+    ///     .extension_flag("SameParty")
+    ///     .build();
+    /// assert_eq!(cookie.extensions().next(), Some(("SameParty", None)));
+    /// ```
+    pub fn extension_flag<N: Into<Cow<'a, str>>>(mut self, name: N) -> Self {
+        self.0.set_extension_flag(name);
+
+        self
+    }
+
     /// Finalizes the builder and returns the constructed `Cookie`.
     ///
     /// # Example
@@ -206,4 +365,31 @@ impl<'a> CookieBuilder<'a> {
     pub fn build(self) -> Cookie<'a> {
         self.0
     }
+
+    /// Finalizes the builder, checking that the constructed `Cookie` is valid.
+    ///
+    /// See [`Cookie::validate`].
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::error::ValidationError;
+    ///
+    /// let result = CookieBuilder::new("session", "abc123")
+    ///     .domain("example.com;")
+    ///     .try_build();
+    /// assert_eq!(result, Err(ValidationError::InvalidDomain(';')));
+    /// ```
+    pub fn try_build(self) -> Result<Cookie<'a>, ValidationError> {
+        self.0.validate()?;
+
+        Ok(self.0)
+    }
+}
+
+fn prefixed(prefix: Prefix, name: Cow<'_, str>) -> Cow<'_, str> {
+    match Prefix::of(&name) == Some(prefix) {
+        true => name,
+        false => Cow::Owned(format!("{prefix}{name}")),
+    }
 }
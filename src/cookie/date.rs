@@ -0,0 +1,164 @@
+//! Parsing of cookie dates.
+//!
+//! This module implements the cookie-date algorithm from
+//! [RFC 6265 §5.1.1](https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.1),
+//! which is what browsers use to interpret the `Expires` attribute. Besides the
+//! IMF-fixdate it accepts the legacy layouts found in the wild, such as RFC 850
+//! (`Sunday, 06-Nov-94 08:49:37 GMT`), asctime (`Sun Nov  6 08:49:37 1994`),
+//! two-digit years and unusual delimiters.
+//!
+//! # Example
+//! ```
+//! use cookie_rs::cookie::date;
+//!
+//! let imf = date::parse("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+//! let rfc850 = date::parse("Sunday, 06-Nov-94 08:49:37 GMT").unwrap();
+//! let asctime = date::parse("Sun Nov  6 08:49:37 1994").unwrap();
+//!
+//! assert_eq!(imf, rfc850);
+//! assert_eq!(imf, asctime);
+//! ```
+use super::expiration::{days_from_civil, days_in_month};
+use super::parse::ParseExpiresError;
+use super::Expiration;
+
+const MONTHS: [&[u8]; 12] = [
+    b"jan", b"feb", b"mar", b"apr", b"may", b"jun", b"jul", b"aug", b"sep", b"oct", b"nov", b"dec",
+];
+
+/// Parses a cookie date following the RFC 6265 §5.1.1 algorithm.
+///
+/// # Arguments
+/// - `value`: The date string, e.g. the value of an `Expires` attribute.
+///
+/// # Returns
+/// A `Result` containing the parsed `Expiration` or a `ParseExpiresError`
+/// describing which step of the algorithm failed.
+///
+/// # Example
+/// ```
+/// use cookie_rs::cookie::date;
+/// use cookie_rs::error::ParseExpiresError;
+///
+/// assert!(date::parse("Thu, 01-Jan-1970 00:00:00 GMT").is_ok());
+/// assert_eq!(date::parse("Jan 1970 00:00:00"), Err(ParseExpiresError::MissingDayOfMonth));
+/// ```
+pub fn parse(value: &str) -> Result<Expiration, ParseExpiresError> {
+    let mut time = None;
+    let mut day_of_month = None;
+    let mut month = None;
+    let mut year = None;
+
+    for token in value.as_bytes().split(|b| is_delimiter(*b)) {
+        if token.is_empty() {
+            continue;
+        }
+
+        if time.is_none() {
+            if let Some(hms) = parse_time(token) {
+                time = Some(hms);
+                continue;
+            }
+        }
+
+        if day_of_month.is_none() {
+            if let Some(day) = parse_digits(token, 1, 2) {
+                day_of_month = Some(day);
+                continue;
+            }
+        }
+
+        if month.is_none() {
+            if let Some(index) = parse_month(token) {
+                month = Some(index);
+                continue;
+            }
+        }
+
+        if year.is_none() {
+            if let Some(value) = parse_digits(token, 2, 4) {
+                year = Some(value);
+                continue;
+            }
+        }
+    }
+
+    let (hour, minute, second) = time.ok_or(ParseExpiresError::MissingTime)?;
+    let day = day_of_month.ok_or(ParseExpiresError::MissingDayOfMonth)?;
+    let month = month.ok_or(ParseExpiresError::MissingMonth)?;
+    let year = match year.ok_or(ParseExpiresError::MissingYear)? {
+        year @ 70..=99 => year + 1900,
+        year @ 0..=69 => year + 2000,
+        year => year,
+    };
+
+    if !(1..=31).contains(&day) {
+        return Err(ParseExpiresError::InvalidDayOfMonth(day));
+    }
+
+    if year < 1601 {
+        return Err(ParseExpiresError::InvalidYear(year));
+    }
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err(ParseExpiresError::InvalidTime(hour, minute, second));
+    }
+
+    if day > days_in_month(i64::from(year), month) {
+        return Err(ParseExpiresError::InvalidDate(year, month, day));
+    }
+
+    let days = days_from_civil(i64::from(year), month, day);
+    let secs = i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+
+    Ok(Expiration::from_unix_timestamp(days * 86_400 + secs))
+}
+
+fn is_delimiter(b: u8) -> bool {
+    matches!(b, 0x09 | 0x20..=0x2F | 0x3B..=0x40 | 0x5B..=0x60 | 0x7B..=0x7E)
+}
+
+/// Matches `1*max DIGIT` of at least `min` digits, optionally followed by a
+/// non-digit and any octets.
+fn parse_digits(token: &[u8], min: usize, max: usize) -> Option<u32> {
+    let len = token.iter().take_while(|b| b.is_ascii_digit()).count();
+
+    if len < min || len > max {
+        return None;
+    }
+
+    Some(
+        token[..len]
+            .iter()
+            .fold(0, |acc, b| acc * 10 + u32::from(b - b'0')),
+    )
+}
+
+/// Matches `hms-time ( non-digit *OCTET )`.
+fn parse_time(token: &[u8]) -> Option<(u32, u32, u32)> {
+    let mut fields = token.splitn(3, |b| *b == b':');
+
+    let hour = fields.next().filter(|f| is_time_field(f))?;
+    let minute = fields.next().filter(|f| is_time_field(f))?;
+    let second = parse_digits(fields.next()?, 1, 2)?;
+
+    Some((
+        parse_digits(hour, 1, 2)?,
+        parse_digits(minute, 1, 2)?,
+        second,
+    ))
+}
+
+fn is_time_field(field: &[u8]) -> bool {
+    (1..=2).contains(&field.len()) && field.iter().all(|b| b.is_ascii_digit())
+}
+
+/// Matches a month name by its first three letters, ignoring case.
+fn parse_month(token: &[u8]) -> Option<u32> {
+    let prefix = token.get(..3)?;
+
+    MONTHS
+        .iter()
+        .position(|m| m.eq_ignore_ascii_case(prefix))
+        .map(|i| i as u32 + 1)
+}
@@ -0,0 +1,306 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::parse::ParseExpiresError;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// The first and last second with a four-digit year, 0001-01-01T00:00:00Z and
+/// 9999-12-31T23:59:59Z, to which formatted dates are clamped.
+const MIN_FORMATTED: i64 = -62_135_596_800;
+const MAX_FORMATTED: i64 = 253_402_300_799;
+
+/// A point in time at which a cookie expires, as carried by the `Expires` attribute.
+///
+/// `Expiration` has a precision of one second and is serialized as an
+/// IMF-fixdate (RFC 1123), e.g. `Tue, 21 Oct 2025 07:28:00 GMT`. With the
+/// `serde` feature, it is (de)serialized as a unix timestamp.
+///
+/// # Example
+/// ```
+/// use cookie_rs::prelude::*;
+///
+/// let expires = Expiration::from_unix_timestamp(1761031680);
+/// assert_eq!(expires.to_string(), "Tue, 21 Oct 2025 07:28:00 GMT");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Expiration(i64);
+
+impl Expiration {
+    /// The Unix epoch, `Thu, 01 Jan 1970 00:00:00 GMT`.
+    pub const UNIX_EPOCH: Self = Self(0);
+
+    /// Creates an `Expiration` from the number of seconds since the Unix epoch.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let expires = Expiration::from_unix_timestamp(0);
+    /// assert_eq!(expires, Expiration::UNIX_EPOCH);
+    /// ```
+    pub const fn from_unix_timestamp(secs: i64) -> Self {
+        Self(secs)
+    }
+
+    /// Returns the number of seconds since the Unix epoch.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let expires = Expiration::from_unix_timestamp(1761031680);
+    /// assert_eq!(expires.unix_timestamp(), 1761031680);
+    /// ```
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns an `Expiration` for the current system time.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// assert!(Expiration::now() > Expiration::UNIX_EPOCH);
+    /// ```
+    pub fn now() -> Self {
+        SystemTime::now().into()
+    }
+
+    /// Returns `true` if this point in time is not after the current system time.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// assert!(Expiration::UNIX_EPOCH.is_expired());
+    /// assert!(!Expiration::from_unix_timestamp(i64::MAX).is_expired());
+    /// ```
+    pub fn is_expired(&self) -> bool {
+        *self <= Self::now()
+    }
+
+    /// Parses an ISO 8601 date-time, as used in HAR files.
+    ///
+    /// Accepts `YYYY-MM-DDTHH:MM:SS` with optional fractional seconds, which are
+    /// truncated, and an optional `Z` or `±HH:MM` offset. A date-time without an
+    /// offset is taken as UTC.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let expires = Expiration::parse_iso8601("2025-10-21T09:28:00.250+02:00").unwrap();
+    /// assert_eq!(expires.unix_timestamp(), 1761031680);
+    ///
+    /// assert!(Expiration::parse_iso8601("21 Oct 2025").is_none());
+    /// ```
+    pub fn parse_iso8601(value: &str) -> Option<Self> {
+        let (date, time) = value.split_once(['T', 't', ' '])?;
+
+        if date.len() != 10 || !date.is_ascii() || &date[4..5] != "-" || &date[7..8] != "-" {
+            return None;
+        }
+
+        let year: i64 = digits(&date[..4])?;
+        let month: u32 = digits(&date[5..7])?;
+        let day: u32 = digits(&date[8..])?;
+
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        let (hms, rest) = (time.get(..8)?, &time[8..]);
+        let [hour, minute, second] = hms.split(':').collect::<Vec<_>>()[..] else {
+            return None;
+        };
+        let (hour, minute, second): (i64, i64, i64) =
+            (digits(hour)?, digits(minute)?, digits(second)?);
+
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+
+        let offset = match rest.strip_prefix('.') {
+            Some(fraction) => fraction.trim_start_matches(|c: char| c.is_ascii_digit()),
+            None => rest,
+        };
+
+        let offset = match offset {
+            "" | "Z" | "z" => 0,
+            offset => {
+                let sign = match offset.as_bytes()[0] {
+                    b'+' => 1,
+                    b'-' => -1,
+                    _ => return None,
+                };
+                let offset = offset[1..].replacen(':', "", 1);
+
+                let hours: i64 = digits(offset.get(..2)?)?;
+                let minutes: i64 = digits(offset.get(2..)?)?;
+
+                sign * (hours * 3600 + minutes * 60)
+            }
+        };
+
+        let days = days_from_civil(year, month, day);
+
+        Some(Self(
+            days * SECS_PER_DAY + hour * 3600 + minute * 60 + second - offset,
+        ))
+    }
+
+    /// Formats this point in time as an ISO 8601 date-time in UTC, as used in HAR files.
+    ///
+    /// Dates before the year 1 or after the year 9999 are clamped to that range.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let expires = Expiration::from_unix_timestamp(1761031680);
+    /// assert_eq!(expires.to_iso8601(), "2025-10-21T07:28:00.000Z");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let timestamp = self.0.clamp(MIN_FORMATTED, MAX_FORMATTED);
+        let days = timestamp.div_euclid(SECS_PER_DAY);
+        let secs = timestamp.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+
+        format!(
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.000Z",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    }
+}
+
+impl From<SystemTime> for Expiration {
+    fn from(value: SystemTime) -> Self {
+        match value.duration_since(UNIX_EPOCH) {
+            Ok(after) => Self(i64::try_from(after.as_secs()).unwrap_or(i64::MAX)),
+            Err(before) => {
+                let before = before.duration();
+                let secs = before.as_secs() + u64::from(before.subsec_nanos() > 0);
+                Self(i64::try_from(secs).map_or(i64::MIN, |s| -s))
+            }
+        }
+    }
+}
+
+/// Converts to a `SystemTime`, saturating at the earliest or latest time the
+/// platform can represent.
+impl From<Expiration> for SystemTime {
+    fn from(value: Expiration) -> Self {
+        let offset = value.0.unsigned_abs();
+
+        match value.0 >= 0 {
+            true => saturating_offset(offset, |d| UNIX_EPOCH.checked_add(d)),
+            false => saturating_offset(offset, |d| UNIX_EPOCH.checked_sub(d)),
+        }
+    }
+}
+
+/// Applies `secs` with `apply`, falling back to the largest number of seconds
+/// that does not overflow.
+fn saturating_offset(secs: u64, apply: impl Fn(Duration) -> Option<SystemTime>) -> SystemTime {
+    if let Some(time) = apply(Duration::from_secs(secs)) {
+        return time;
+    }
+
+    let (mut low, mut high) = (0, secs);
+
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+
+        match apply(Duration::from_secs(mid)) {
+            Some(_) => low = mid,
+            None => high = mid - 1,
+        }
+    }
+
+    apply(Duration::from_secs(low)).unwrap_or(UNIX_EPOCH)
+}
+
+impl fmt::Display for Expiration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timestamp = self.0.clamp(MIN_FORMATTED, MAX_FORMATTED);
+        let days = timestamp.div_euclid(SECS_PER_DAY);
+        let secs = timestamp.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        let weekday = WEEKDAYS[(days + 4).rem_euclid(7) as usize];
+
+        write!(
+            f,
+            "{weekday}, {day:02} {} {year:04} {:02}:{:02}:{:02} GMT",
+            MONTHS[month as usize - 1],
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    }
+}
+
+impl std::str::FromStr for Expiration {
+    type Err = ParseExpiresError;
+
+    /// Parses a cookie date, see [`date::parse`](super::date::parse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        super::date::parse(s)
+    }
+}
+
+/// Parses a fixed-width run of two or four ASCII digits.
+fn digits<T: std::str::FromStr>(value: &str) -> Option<T> {
+    let valid = matches!(value.len(), 2 | 4) && value.bytes().all(|b| b.is_ascii_digit());
+
+    valid.then(|| value.parse().ok())?
+}
+
+pub(crate) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts a proleptic Gregorian date into days since the Unix epoch.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+/// Converts days since the Unix epoch into a proleptic Gregorian date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
@@ -0,0 +1,24 @@
+use http::header::InvalidHeaderValue;
+use http::HeaderValue;
+
+use super::Cookie;
+
+impl TryFrom<&Cookie<'_>> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    /// Converts a cookie into a `Set-Cookie` header value.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use http::HeaderValue;
+    ///
+    /// let cookie = Cookie::builder("session", "abc123").path("/").build();
+    /// let value = HeaderValue::try_from(&cookie).unwrap();
+    ///
+    /// assert_eq!(value, "session=abc123; Path=/");
+    /// ```
+    fn try_from(value: &Cookie<'_>) -> Result<Self, Self::Error> {
+        HeaderValue::try_from(value.to_string())
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! # Features
 //! - Flexible parsing with `parse` and `parse_strict` methods.
-//! - Detailed error handling using `ParseError` and `ParseSameSiteError`.
+//! - Detailed error handling using `ParseError`, `ParseSameSiteError` and `ParsePriorityError`.
 //! - Support for common cookie attributes.
 //!
 //! # Example
@@ -20,11 +20,17 @@
 //! assert_eq!(cookie.secure(), Some(true));
 //! ```
 use std::borrow::Cow;
+use std::sync::Arc;
 use std::time::Duration;
 
+#[cfg(not(feature = "percent-encoding"))]
+use super::validate::validate_value;
+use super::validate::{validate_domain, validate_name, validate_path};
 use super::Cookie;
+use super::Expiration;
+use super::Priority;
 use super::SameSite;
-use crate::StringPrison;
+use crate::CookieStr;
 
 pub use self::error::*;
 
@@ -33,7 +39,8 @@ pub mod error;
 impl<'a> Cookie<'a> {
     /// Parses a cookie from a string in a lenient mode.
     ///
-    /// In lenient mode, unknown attributes are ignored.
+    /// In lenient mode, unparseable `Expires` dates and invalid `Priority` values are
+    /// ignored and unknown attributes are kept in order, see [`Cookie::extensions`].
     ///
     /// # Arguments
     /// - `value`: The string representation of the cookie.
@@ -51,12 +58,14 @@ impl<'a> Cookie<'a> {
     /// assert_eq!(cookie.secure(), Some(true));
     /// ```
     pub fn parse<V: Into<Cow<'a, str>>>(value: V) -> Result<Self, ParseError> {
-        Self::inner_parse(value.into(), false)
+        Self::parse_spanned(value).map_err(Into::into)
     }
 
     /// Parses a cookie from a string in a strict mode.
     ///
-    /// In strict mode, unknown attributes cause an error.
+    /// In strict mode, unknown attributes, unparseable `Expires` dates, invalid
+    /// `Priority` values and cookies failing [`Cookie::validate`] cause an error. This includes names that are
+    /// not a valid `token` and violations of a `__Host-` or `__Secure-` name prefix.
     ///
     /// # Arguments
     /// - `value`: The string representation of the cookie.
@@ -72,35 +81,113 @@ impl<'a> Cookie<'a> {
     /// assert!(result.is_err());
     /// ```
     pub fn parse_strict<V: Into<Cow<'a, str>>>(value: V) -> Result<Self, ParseError> {
-        Self::inner_parse(value.into(), true)
+        Self::parse_strict_spanned(value).map_err(Into::into)
     }
 
-    pub(crate) fn inner_parse(value: Cow<'a, str>, strict: bool) -> Result<Self, ParseError> {
-        let prison = StringPrison::new(value);
+    /// Parses a cookie like [`Cookie::parse`], reporting where parsing failed.
+    ///
+    /// # Arguments
+    /// - `value`: The string representation of the cookie.
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed `Cookie` or a `SpannedParseError` with
+    /// the byte range and attribute index of the offending token.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let err = Cookie::parse_spanned("session=abc123; Max-Age=soon").unwrap_err();
+    ///
+    /// assert_eq!(err.span().range(), 24..28);
+    /// assert_eq!(err.span().attribute(), 1);
+    /// ```
+    pub fn parse_spanned<V: Into<Cow<'a, str>>>(value: V) -> Result<Self, SpannedParseError> {
+        Self::inner_parse(value.into(), false, false)
+    }
 
-        // SAFETY: prison and slice owned by the same struct
-        let str = unsafe { prison.get() };
+    /// Parses a cookie like [`Cookie::parse_strict`], reporting where parsing failed.
+    ///
+    /// # Arguments
+    /// - `value`: The string representation of the cookie.
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed `Cookie` or a `SpannedParseError` with
+    /// the byte range and attribute index of the offending token.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let input = "session=abc123; Path=/; UnknownAttr";
+    /// let err = Cookie::parse_strict_spanned(input).unwrap_err();
+    ///
+    /// assert_eq!(&input[err.span().range()], "UnknownAttr");
+    /// assert_eq!(err.span().attribute(), 2);
+    /// ```
+    pub fn parse_strict_spanned<V: Into<Cow<'a, str>>>(
+        value: V,
+    ) -> Result<Self, SpannedParseError> {
+        Self::inner_parse(value.into(), true, true)
+    }
+
+    /// Parses a cookie, checking it against [`Cookie::validate`] if `validate` is set.
+    pub(crate) fn inner_parse(
+        value: Cow<'a, str>,
+        strict: bool,
+        validate: bool,
+    ) -> Result<Self, SpannedParseError> {
+        match value {
+            Cow::Borrowed(str) => parse_cookie(str, strict, validate),
+            Cow::Owned(string) => {
+                let buffer = Arc::from(string);
 
-        let mut cookie = parse_cookie(str, strict)?;
-        cookie.prison = Some(prison);
+                Ok(parse_cookie(&buffer, strict, validate)?.share(&buffer))
+            }
+        }
+    }
+}
 
-        Ok(cookie)
+impl Cookie<'_> {
+    /// Turns attributes borrowed from `buffer` into ranges of it, see [`CookieStr::share`].
+    pub(crate) fn share(self, buffer: &Arc<str>) -> Cookie<'static> {
+        Cookie {
+            name: self.name.share(buffer),
+            value: self.value.share(buffer),
+            domain: self.domain.map(|v| v.share(buffer)),
+            expires_raw: self.expires_raw.map(|v| v.share(buffer)),
+            path: self.path.map(|v| v.share(buffer)),
+            extensions: self
+                .extensions
+                .into_iter()
+                .map(|(name, value)| (name.share(buffer), value.map(|v| v.share(buffer))))
+                .collect(),
+            ..self
+        }
     }
 }
 
-fn parse_cookie(str: &str, strict: bool) -> Result<Cookie<'_>, ParseError> {
+fn parse_cookie(str: &str, strict: bool, validate: bool) -> Result<Cookie<'_>, SpannedParseError> {
     let mut attributes = str.split(';');
+    let pair = attributes.next().expect("Missing any attributes").trim();
 
-    let (name, value) = attributes
-        .next()
-        .expect("Missing any attributes")
+    let (name, value) = pair
         .split_once('=')
-        .ok_or(MissingPair::NameValue)?;
+        .ok_or_else(|| at(str, pair, 0)(MissingPair::NameValue))?;
 
     let (name, value) = (name.trim(), value.trim());
 
     if name.is_empty() {
-        return Err(ParseError::EmptyName);
+        return Err(at(str, pair, 0)(ParseError::EmptyName));
+    }
+
+    if strict {
+        validate_name(name).map_err(at(str, name, 0))?;
+    }
+
+    #[cfg(not(feature = "percent-encoding"))]
+    if validate {
+        validate_value(value).map_err(at(str, value, 0))?;
     }
 
     #[cfg(not(feature = "percent-encoding"))]
@@ -108,12 +195,11 @@ fn parse_cookie(str: &str, strict: bool) -> Result<Cookie<'_>, ParseError> {
     #[cfg(feature = "percent-encoding")]
     let mut cookie = Cookie::new(
         name,
-        percent_encoding::percent_decode_str(value)
-            .decode_utf8()
-            .map_err(|_| ParseError::ParseDecodeError)?,
+        super::decode_value(value)
+            .ok_or_else(|| at(str, value, 0)(ParseError::ParseDecodeError))?,
     );
 
-    for attribute in attributes {
+    for (index, attribute) in attributes.enumerate().map(|(i, a)| (i + 1, a)) {
         let mut pair = attribute.splitn(2, '=');
 
         let (name, value) = (
@@ -121,34 +207,169 @@ fn parse_cookie(str: &str, strict: bool) -> Result<Cookie<'_>, ParseError> {
             pair.next().map(|v| v.trim()),
         );
 
+        let value_part = value.unwrap_or(name);
+
         match value {
             domain if name.eq_ignore_ascii_case("Domain") => {
-                cookie.set_domain(domain.ok_or(MissingPair::Domain)?)
+                let domain = domain
+                    .ok_or(MissingPair::Domain)
+                    .map_err(at(str, name, index))?;
+
+                if validate {
+                    validate_domain(domain).map_err(at(str, value_part, index))?;
+                }
+
+                cookie.set_domain(domain)
             }
             expires if name.eq_ignore_ascii_case("Expires") => {
-                cookie.set_expires(expires.ok_or(MissingPair::Expires)?)
+                let expires = expires
+                    .ok_or(MissingPair::Expires)
+                    .map_err(at(str, name, index))?;
+
+                match expires.parse::<Expiration>() {
+                    Ok(parsed) => {
+                        cookie.set_expires(parsed);
+                        cookie.expires_raw = Some(CookieStr::Cow(expires.into()));
+                    }
+                    Err(err) if strict => return Err(at(str, value_part, index)(err)),
+                    Err(_) => continue,
+                }
             }
             _ if name.eq_ignore_ascii_case("HttpOnly") => cookie.set_http_only(true),
             max_age if name.eq_ignore_ascii_case("Max-Age") => {
-                let secs: i64 = max_age.ok_or(MissingPair::MaxAge)?.parse()?;
+                let max_age = max_age
+                    .ok_or(MissingPair::MaxAge)
+                    .map_err(at(str, name, index))?;
+                let secs: i64 = max_age.parse().map_err(at(str, value_part, index))?;
                 cookie.set_max_age(Duration::from_secs(secs.max(0) as u64))
             }
             _ if name.eq_ignore_ascii_case("Partitioned") => cookie.set_partitioned(true),
             path if name.eq_ignore_ascii_case("Path") => {
-                cookie.set_path(path.ok_or(MissingPair::Path)?)
+                let path = path
+                    .ok_or(MissingPair::Path)
+                    .map_err(at(str, name, index))?;
+
+                if validate {
+                    validate_path(path).map_err(at(str, value_part, index))?;
+                }
+
+                cookie.set_path(path)
+            }
+            priority if name.eq_ignore_ascii_case("Priority") => {
+                let parsed = priority
+                    .ok_or(MissingPair::Priority)
+                    .map_err(at(str, name, index))
+                    .and_then(|p| p.parse::<Priority>().map_err(at(str, value_part, index)));
+
+                // browsers fall back to the default priority on an invalid value
+                match parsed {
+                    Ok(priority) => cookie.set_priority(priority),
+                    Err(err) if strict => return Err(err),
+                    Err(_) => continue,
+                }
             }
             _ if name.eq_ignore_ascii_case("Secure") => cookie.set_secure(true),
             same_site if name.eq_ignore_ascii_case("SameSite") => {
-                cookie.set_same_site(same_site.ok_or(MissingPair::SameSite)?.parse()?)
+                let same_site = same_site
+                    .ok_or(MissingPair::SameSite)
+                    .map_err(at(str, name, index))?;
+                cookie.set_same_site(same_site.parse().map_err(at(str, value_part, index))?)
+            }
+            _ if strict => {
+                return Err(at(str, name, index)(ParseError::UnknownAttribute(
+                    name.to_owned(),
+                )))
             }
-            _ if strict => return Err(ParseError::UnknownAttribute(name.to_owned())),
-            _ => continue,
+            _ if name.is_empty() => continue,
+            value => cookie.extensions.push((
+                CookieStr::Cow(name.into()),
+                value.map(|v| CookieStr::Cow(v.into())),
+            )),
         }
     }
 
+    if validate {
+        cookie.validate_prefix().map_err(at(str, name, 0))?;
+    }
+
     Ok(cookie)
 }
 
+/// The known attributes that take a value.
+const VALUED_ATTRIBUTES: [&str; 6] = [
+    "Domain", "Expires", "Max-Age", "Path", "Priority", "SameSite",
+];
+
+impl<'a> Cookie<'a> {
+    /// Sets the known attribute `name` the way the lenient parser reads it,
+    /// ignoring a value that is invalid for the attribute.
+    ///
+    /// Returns `false` if `name` is not a known attribute.
+    pub(crate) fn set_known_attribute(&mut self, name: &str, value: Option<Cow<'a, str>>) -> bool {
+        let is = |attribute: &str| name.eq_ignore_ascii_case(attribute);
+
+        match value {
+            _ if is("HttpOnly") => self.set_http_only(true),
+            _ if is("Partitioned") => self.set_partitioned(true),
+            _ if is("Secure") => self.set_secure(true),
+            Some(domain) if is("Domain") => self.set_domain(domain),
+            Some(path) if is("Path") => self.set_path(path),
+            Some(expires) if is("Expires") => {
+                if let Ok(expires) = expires.parse::<Expiration>() {
+                    self.set_expires(expires);
+                }
+            }
+            Some(max_age) if is("Max-Age") => {
+                if let Ok(secs) = max_age.parse::<i64>() {
+                    self.set_max_age(Duration::from_secs(secs.max(0) as u64));
+                }
+            }
+            Some(priority) if is("Priority") => {
+                if let Ok(priority) = priority.parse() {
+                    self.set_priority(priority);
+                }
+            }
+            Some(same_site) if is("SameSite") => {
+                if let Ok(same_site) = same_site.parse() {
+                    self.set_same_site(same_site);
+                }
+            }
+            _ => return VALUED_ATTRIBUTES.into_iter().any(is),
+        }
+
+        true
+    }
+}
+
+/// Returns a function attaching the location of `part`, a slice of `str`, to an error.
+fn at<'s, E: Into<ParseError>>(
+    str: &'s str,
+    part: &'s str,
+    attribute: usize,
+) -> impl FnOnce(E) -> SpannedParseError + 's {
+    move |err| {
+        let start = part.as_ptr() as usize - str.as_ptr() as usize;
+
+        SpannedParseError::new(err.into(), Span::new(start..start + part.len(), attribute))
+    }
+}
+
+impl std::str::FromStr for Priority {
+    type Err = ParsePriorityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("low") {
+            Ok(Self::Low)
+        } else if s.eq_ignore_ascii_case("medium") {
+            Ok(Self::Medium)
+        } else if s.eq_ignore_ascii_case("high") {
+            Ok(Self::High)
+        } else {
+            Err(ParsePriorityError::UnknownValue(s.to_owned()))
+        }
+    }
+}
+
 impl std::str::FromStr for SameSite {
     type Err = ParseSameSiteError;
 
@@ -1,6 +1,10 @@
 use std::error::Error;
 use std::fmt;
 use std::num::ParseIntError;
+use std::ops::Range;
+
+use crate::cookie::prefix::PrefixError;
+use crate::cookie::validate::ValidationError;
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -10,7 +14,43 @@ pub enum ParseError {
     UnknownAttribute(String),
     ParseMaxAgeError(ParseIntError),
     ParseSameSiteError(ParseSameSiteError),
+    ParsePriorityError(ParsePriorityError),
+    ParseExpiresError(ParseExpiresError),
     ParseDecodeError,
+    PrefixError(PrefixError),
+    ValidationError(ValidationError),
+}
+
+/// The location of a parse error in the parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    start: usize,
+    end: usize,
+    attribute: usize,
+}
+
+/// A `ParseError` with the location of the token that caused it.
+///
+/// # Example
+/// ```
+/// use cookie_rs::prelude::*;
+///
+/// let input = "session=abc123; Path=/; SameSite=Sometimes";
+/// let err = Cookie::parse_spanned(input).unwrap_err();
+///
+/// assert_eq!(
+///     err.render(input),
+///     "error: failed to parse SameSite: unknown SameSite value: Sometimes\n \
+///      --> attribute 2, bytes 33..42\n  \
+///        |\n  \
+///        | session=abc123; Path=/; SameSite=Sometimes\n  \
+///        |                                  ^^^^^^^^^\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpannedParseError {
+    error: ParseError,
+    span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,6 +58,23 @@ pub enum ParseSameSiteError {
     UnknownValue(String),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePriorityError {
+    UnknownValue(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseExpiresError {
+    MissingTime,
+    MissingDayOfMonth,
+    MissingMonth,
+    MissingYear,
+    InvalidDayOfMonth(u32),
+    InvalidYear(u32),
+    InvalidTime(u32, u32, u32),
+    InvalidDate(u32, u32, u32),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MissingPair {
     NameValue,
@@ -25,13 +82,140 @@ pub enum MissingPair {
     Expires,
     MaxAge,
     Path,
+    Priority,
     SameSite,
 }
 
+impl Span {
+    pub(crate) fn new(range: Range<usize>, attribute: usize) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+            attribute,
+        }
+    }
+
+    /// Returns the byte range of the offending token.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the index of the `;`-separated attribute the token belongs to.
+    ///
+    /// For a cookie the name-value pair is attribute `0`; for a `Cookie`
+    /// request header this is the index of the pair.
+    pub fn attribute(&self) -> usize {
+        self.attribute
+    }
+}
+
+/// Characters of context shown on each side of the span by [`SpannedParseError::render`].
+const RENDER_CONTEXT: usize = 40;
+
+impl SpannedParseError {
+    pub(crate) fn new(error: ParseError, span: Span) -> Self {
+        Self { error, span }
+    }
+
+    /// Moves the span by `offset` bytes and replaces its attribute index.
+    pub(crate) fn shift(self, offset: usize, attribute: usize) -> Self {
+        let span = Span::new(self.span.start + offset..self.span.end + offset, attribute);
+
+        Self::new(self.error, span)
+    }
+
+    /// Returns the underlying error.
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    /// Returns the location of the error.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Converts into the underlying error, dropping the location.
+    pub fn into_error(self) -> ParseError {
+        self.error
+    }
+
+    /// Renders the error with the parsed string and a caret under the offending token.
+    ///
+    /// Only the surroundings of the token are shown for long strings, and
+    /// control characters are replaced so the caret stays aligned.
+    ///
+    /// # Arguments
+    /// - `input`: The string that failed to parse.
+    pub fn render(&self, input: &str) -> String {
+        let start = self.span.start.min(input.len());
+        let end = self.span.end.clamp(start, input.len());
+
+        let (Some(before), Some(token), Some(after)) =
+            (input.get(..start), input.get(start..end), input.get(end..))
+        else {
+            return format!("error: {}\n", self.error);
+        };
+
+        let before = before.chars().collect::<Vec<_>>();
+        let head = &before[before.len().saturating_sub(RENDER_CONTEXT)..];
+        let prefix = if head.len() < before.len() { "..." } else { "" };
+        let suffix = if after.chars().count() > RENDER_CONTEXT {
+            "..."
+        } else {
+            ""
+        };
+
+        let line = format!(
+            "{prefix}{}{}{}{suffix}",
+            head.iter().copied().map(visible).collect::<String>(),
+            token.chars().map(visible).collect::<String>(),
+            after
+                .chars()
+                .take(RENDER_CONTEXT)
+                .map(visible)
+                .collect::<String>(),
+        );
+
+        format!(
+            "error: {}\n --> attribute {}, bytes {}..{}\n  |\n  | {line}\n  | {}{}\n",
+            self.error,
+            self.span.attribute,
+            self.span.start,
+            self.span.end,
+            " ".repeat(prefix.len() + head.len()),
+            "^".repeat(token.chars().count().max(1)),
+        )
+    }
+}
+
+/// Replaces control characters, which would break the caret alignment.
+fn visible(c: char) -> char {
+    match c.is_control() {
+        true => char::REPLACEMENT_CHARACTER,
+        false => c,
+    }
+}
+
 impl Error for ParseError {}
 
+impl Error for SpannedParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<SpannedParseError> for ParseError {
+    fn from(value: SpannedParseError) -> Self {
+        value.error
+    }
+}
+
 impl Error for ParseSameSiteError {}
 
+impl Error for ParsePriorityError {}
+
+impl Error for ParseExpiresError {}
+
 impl From<MissingPair> for ParseError {
     fn from(value: MissingPair) -> Self {
         Self::MissingPair(value)
@@ -50,6 +234,34 @@ impl From<ParseSameSiteError> for ParseError {
     }
 }
 
+impl From<ParsePriorityError> for ParseError {
+    fn from(value: ParsePriorityError) -> Self {
+        Self::ParsePriorityError(value)
+    }
+}
+
+impl From<ParseExpiresError> for ParseError {
+    fn from(value: ParseExpiresError) -> Self {
+        Self::ParseExpiresError(value)
+    }
+}
+
+impl From<PrefixError> for ParseError {
+    fn from(value: PrefixError) -> Self {
+        Self::PrefixError(value)
+    }
+}
+
+impl From<ValidationError> for ParseError {
+    fn from(value: ValidationError) -> Self {
+        match value {
+            ValidationError::EmptyName => Self::EmptyName,
+            ValidationError::PrefixError(err) => Self::PrefixError(err),
+            err => Self::ValidationError(err),
+        }
+    }
+}
+
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -58,11 +270,25 @@ impl fmt::Display for ParseError {
             ParseError::UnknownAttribute(attr) => write!(f, "unknown attribute: {attr}"),
             ParseError::ParseMaxAgeError(err) => write!(f, "failed to parse Max-Age: {err}"),
             ParseError::ParseSameSiteError(err) => write!(f, "failed to parse SameSite: {err}"),
+            ParseError::ParsePriorityError(err) => write!(f, "failed to parse Priority: {err}"),
+            ParseError::ParseExpiresError(err) => write!(f, "failed to parse Expires: {err}"),
             ParseError::ParseDecodeError => write!(f, "failed to decode percent-encoded value."),
+            ParseError::PrefixError(err) => write!(f, "invalid prefixed cookie: {err}"),
+            ParseError::ValidationError(err) => write!(f, "invalid cookie: {err}"),
         }
     }
 }
 
+impl fmt::Display for SpannedParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (attribute {}, bytes {}..{})",
+            self.error, self.span.attribute, self.span.start, self.span.end
+        )
+    }
+}
+
 impl fmt::Display for ParseSameSiteError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -73,6 +299,35 @@ impl fmt::Display for ParseSameSiteError {
     }
 }
 
+impl fmt::Display for ParsePriorityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePriorityError::UnknownValue(value) => {
+                write!(f, "unknown Priority value: {value}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseExpiresError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseExpiresError::MissingTime => write!(f, "missing time of day"),
+            ParseExpiresError::MissingDayOfMonth => write!(f, "missing day of month"),
+            ParseExpiresError::MissingMonth => write!(f, "missing month"),
+            ParseExpiresError::MissingYear => write!(f, "missing year"),
+            ParseExpiresError::InvalidDayOfMonth(day) => write!(f, "invalid day of month: {day}"),
+            ParseExpiresError::InvalidYear(year) => write!(f, "invalid year: {year}"),
+            ParseExpiresError::InvalidTime(h, m, s) => {
+                write!(f, "invalid time of day: {h:02}:{m:02}:{s:02}")
+            }
+            ParseExpiresError::InvalidDate(y, m, d) => {
+                write!(f, "nonexistent date: {y:04}-{m:02}-{d:02}")
+            }
+        }
+    }
+}
+
 impl fmt::Display for MissingPair {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let pair_name = match self {
@@ -81,6 +336,7 @@ impl fmt::Display for MissingPair {
             MissingPair::Expires => "Expires",
             MissingPair::MaxAge => "Max-Age",
             MissingPair::Path => "Path",
+            MissingPair::Priority => "Priority",
             MissingPair::SameSite => "SameSite",
         };
         write!(f, "{pair_name}")
@@ -0,0 +1,147 @@
+//! Cookie name prefixes.
+//!
+//! Cookies whose names start with `__Host-` or `__Secure-` carry guarantees
+//! that browsers enforce: a `__Secure-` cookie must be `Secure`, and a
+//! `__Host-` cookie must additionally have `Path=/` and no `Domain`.
+//!
+//! # Example
+//! ```
+//! use cookie_rs::prelude::*;
+//!
+//! let cookie = CookieBuilder::host_prefixed("session", "abc123").build();
+//! assert_eq!(cookie.name(), "__Host-session");
+//! assert!(cookie.validate_prefix().is_ok());
+//!
+//! let cookie = Cookie::new("__Secure-id", "1");
+//! assert!(cookie.validate_prefix().is_err());
+//! ```
+use std::error::Error;
+use std::fmt;
+
+use super::Cookie;
+
+/// A cookie name prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prefix {
+    Host,
+    Secure,
+}
+
+/// A violation of the requirements of a cookie name prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrefixError {
+    NotSecure(Prefix),
+    PathNotRoot,
+    HasDomain,
+}
+
+impl Prefix {
+    /// Returns the prefix as it appears in a cookie name.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::cookie::prefix::Prefix;
+    ///
+    /// assert_eq!(Prefix::Host.as_str(), "__Host-");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Prefix::Host => "__Host-",
+            Prefix::Secure => "__Secure-",
+        }
+    }
+
+    /// Returns the prefix of a cookie name, if any.
+    ///
+    /// Prefixes are matched case-insensitively, as browsers do.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::cookie::prefix::Prefix;
+    ///
+    /// assert_eq!(Prefix::of("__Host-session"), Some(Prefix::Host));
+    /// assert_eq!(Prefix::of("__secure-id"), Some(Prefix::Secure));
+    /// assert_eq!(Prefix::of("session"), None);
+    /// ```
+    pub fn of(name: &str) -> Option<Self> {
+        [Prefix::Host, Prefix::Secure].into_iter().find(|prefix| {
+            let prefix = prefix.as_str();
+
+            name.get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+        })
+    }
+}
+
+impl Cookie<'_> {
+    /// Returns the name prefix of the cookie, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::cookie::prefix::Prefix;
+    ///
+    /// let cookie = Cookie::new("__Secure-id", "1");
+    /// assert_eq!(cookie.prefix(), Some(Prefix::Secure));
+    /// ```
+    pub fn prefix(&self) -> Option<Prefix> {
+        Prefix::of(self.name())
+    }
+
+    /// Checks that the cookie satisfies the requirements of its name prefix.
+    ///
+    /// Cookies without a prefix always pass.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::error::PrefixError;
+    ///
+    /// let cookie = Cookie::builder("__Host-id", "1")
+    ///     .secure(true)
+    ///     .path("/")
+    ///     .domain("example.com")
+    ///     .build();
+    ///
+    /// assert_eq!(cookie.validate_prefix(), Err(PrefixError::HasDomain));
+    /// ```
+    pub fn validate_prefix(&self) -> Result<(), PrefixError> {
+        let Some(prefix) = self.prefix() else {
+            return Ok(());
+        };
+
+        if !self.secure().is_some_and(|v| v) {
+            return Err(PrefixError::NotSecure(prefix));
+        }
+
+        if prefix == Prefix::Host {
+            if self.path() != Some("/") {
+                return Err(PrefixError::PathNotRoot);
+            }
+
+            if self.domain().is_some() {
+                return Err(PrefixError::HasDomain);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Error for PrefixError {}
+
+impl fmt::Display for PrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrefixError::NotSecure(prefix) => write!(f, "{prefix} cookie must be Secure."),
+            PrefixError::PathNotRoot => write!(f, "__Host- cookie must have Path=/."),
+            PrefixError::HasDomain => write!(f, "__Host- cookie must not have a Domain."),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Cookie, Expiration, Priority, SameSite};
+
+/// The serialized form of a [`Cookie`], with one field per attribute.
+///
+/// `max_age` is stored in seconds and `expires` as a unix timestamp.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Cookie")]
+struct CookieRepr<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    #[serde(borrow)]
+    value: Cow<'a, str>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    domain: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires: Option<Expiration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    http_only: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_age: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    partitioned: Option<bool>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    path: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<Priority>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    same_site: Option<SameSite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    secure: Option<bool>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    extensions: Vec<(CowStr<'a>, Option<CowStr<'a>>)>,
+}
+
+/// A string that borrows from the input where possible, also when nested in
+/// other types.
+///
+/// `#[serde(borrow)]` only borrows a bare `Cow<str>`, not one wrapped in an
+/// `Option`, a tuple or a `Vec`.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct CowStr<'a>(#[serde(borrow)] Cow<'a, str>);
+
+impl Serialize for Cookie<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CookieRepr {
+            name: Cow::Borrowed(self.name()),
+            value: Cow::Borrowed(self.value()),
+            domain: self.domain().map(Cow::Borrowed),
+            expires: self.expires(),
+            http_only: self.http_only,
+            max_age: self.max_age.map(|v| v.as_secs()),
+            partitioned: self.partitioned,
+            path: self.path().map(Cow::Borrowed),
+            priority: self.priority,
+            same_site: self.same_site,
+            secure: self.secure,
+            extensions: self
+                .extensions()
+                .map(|(name, value)| {
+                    let borrowed = |v| CowStr(Cow::Borrowed(v));
+
+                    (borrowed(name), value.map(borrowed))
+                })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for Cookie<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CookieRepr::deserialize(deserializer)?;
+
+        let extensions = repr
+            .extensions
+            .into_iter()
+            .map(|(name, value)| (name.0.into(), value.map(|v| v.0.into())))
+            .collect();
+
+        Ok(Self {
+            name: repr.name.into(),
+            value: repr.value.into(),
+            domain: repr.domain.map(Into::into),
+            expires: repr.expires,
+            http_only: repr.http_only,
+            max_age: repr.max_age.map(Duration::from_secs),
+            partitioned: repr.partitioned,
+            path: repr.path.map(Into::into),
+            priority: repr.priority,
+            same_site: repr.same_site,
+            secure: repr.secure,
+            extensions,
+            ..Default::default()
+        })
+    }
+}
+
+/// Deserializes an optional string, borrowing from the input where possible.
+fn borrow_option<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<CowStr>::deserialize(deserializer).map(|v| v.map(|v| v.0))
+}
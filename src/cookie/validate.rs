@@ -0,0 +1,155 @@
+//! Validation of cookies against the RFC 6265 grammar.
+//!
+//! A cookie name must be an RFC 7230 `token`, and a value must consist of
+//! `cookie-octet`s, optionally wrapped in double quotes. `Domain` and `Path`
+//! and extension attributes may not contain control characters or `;`. Without these checks a name or
+//! attribute containing `;` or CRLF would be written verbatim into the
+//! `Set-Cookie` header.
+//!
+//! # Example
+//! ```
+//! use cookie_rs::prelude::*;
+//! use cookie_rs::error::ValidationError;
+//!
+//! assert!(Cookie::new("session", "abc123").validate().is_ok());
+//! assert_eq!(
+//!     Cookie::new("session id", "abc123").validate(),
+//!     Err(ValidationError::InvalidName(' '))
+//! );
+//! ```
+use std::error::Error;
+use std::fmt;
+
+use super::prefix::PrefixError;
+use super::Cookie;
+
+/// An error describing why a cookie cannot be safely serialized.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    EmptyName,
+    InvalidName(char),
+    InvalidValue(char),
+    InvalidDomain(char),
+    InvalidPath(char),
+    InvalidExtension(char),
+    PrefixError(PrefixError),
+}
+
+impl Cookie<'_> {
+    /// Checks that the cookie can be serialized into a valid `Set-Cookie` header.
+    ///
+    /// The name must be a `token`, the `Domain`, `Path` and extension attributes
+    /// must not contain control characters or `;`, extension names must not
+    /// contain `=`, and the cookie must satisfy the
+    /// requirements of its name prefix. The value is only checked against
+    /// `cookie-octet` when the `percent-encoding` feature is disabled, as it is
+    /// encoded otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::error::ValidationError;
+    ///
+    /// let cookie = Cookie::builder("session", "abc123").path("/\r\nX-Injected: 1").build();
+    /// assert_eq!(cookie.validate(), Err(ValidationError::InvalidPath('\r')));
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_name(self.name())?;
+
+        #[cfg(not(feature = "percent-encoding"))]
+        validate_value(self.value())?;
+
+        if let Some(domain) = self.domain() {
+            validate_domain(domain)?;
+        }
+
+        if let Some(path) = self.path() {
+            validate_path(path)?;
+        }
+
+        for (name, value) in self.extensions() {
+            find_invalid(name, |c| is_av_octet(c) && c != b'=')
+                .or_else(|| value.and_then(|v| find_invalid(v, is_av_octet)))
+                .map_or(Ok(()), |c| Err(ValidationError::InvalidExtension(c)))?;
+        }
+
+        self.validate_prefix()?;
+
+        Ok(())
+    }
+}
+
+/// Checks that `name` is a non-empty RFC 7230 `token`.
+pub(crate) fn validate_name(name: &str) -> Result<(), ValidationError> {
+    if name.is_empty() {
+        return Err(ValidationError::EmptyName);
+    }
+
+    find_invalid(name, is_token_char).map_or(Ok(()), |c| Err(ValidationError::InvalidName(c)))
+}
+
+/// Checks that `value` is a sequence of `cookie-octet`s, optionally in double quotes.
+#[cfg_attr(feature = "percent-encoding", allow(dead_code))]
+pub(crate) fn validate_value(value: &str) -> Result<(), ValidationError> {
+    let unquoted = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value);
+
+    find_invalid(unquoted, is_cookie_octet)
+        .map_or(Ok(()), |c| Err(ValidationError::InvalidValue(c)))
+}
+
+/// Checks that a `Domain` attribute has no control characters or `;`.
+pub(crate) fn validate_domain(domain: &str) -> Result<(), ValidationError> {
+    find_invalid(domain, is_av_octet).map_or(Ok(()), |c| Err(ValidationError::InvalidDomain(c)))
+}
+
+/// Checks that a `Path` attribute has no control characters or `;`.
+pub(crate) fn validate_path(path: &str) -> Result<(), ValidationError> {
+    find_invalid(path, is_av_octet).map_or(Ok(()), |c| Err(ValidationError::InvalidPath(c)))
+}
+
+fn find_invalid(str: &str, is_valid: fn(u8) -> bool) -> Option<char> {
+    str.chars().find(|&c| !c.is_ascii() || !is_valid(c as u8))
+}
+
+/// `tchar` from RFC 7230, section 3.2.6.
+fn is_token_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&c)
+}
+
+/// `cookie-octet` from RFC 6265, section 4.1.1.
+fn is_cookie_octet(c: u8) -> bool {
+    matches!(c, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E)
+}
+
+/// Any `CHAR` except `CTL`s or `;`, as allowed in attribute values.
+fn is_av_octet(c: u8) -> bool {
+    !c.is_ascii_control() && c != b';'
+}
+
+impl From<PrefixError> for ValidationError {
+    fn from(value: PrefixError) -> Self {
+        Self::PrefixError(value)
+    }
+}
+
+impl Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::EmptyName => write!(f, "cookie name is empty."),
+            ValidationError::InvalidName(c) => write!(f, "invalid character in name: {c:?}"),
+            ValidationError::InvalidValue(c) => write!(f, "invalid character in value: {c:?}"),
+            ValidationError::InvalidDomain(c) => write!(f, "invalid character in Domain: {c:?}"),
+            ValidationError::InvalidPath(c) => write!(f, "invalid character in Path: {c:?}"),
+            ValidationError::InvalidExtension(c) => {
+                write!(f, "invalid character in extension attribute: {c:?}")
+            }
+            ValidationError::PrefixError(err) => write!(f, "{err}"),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! HAR (HTTP Archive) cookie support.
+//!
+//! Browsers record sessions as [HAR 1.2](http://www.softwareishard.com/blog/har-12-spec/)
+//! files, where every request and response lists its cookies as objects.
+//! [`HarCookie`] mirrors such an object and converts to and from [`Cookie`],
+//! and [`cookie_jars`] collects the cookies of every entry of a HAR log.
+//!
+//! # Example
+//! ```
+//! use cookie_rs::har;
+//!
+//! let log = r#"{"log": {"entries": [{
+//!     "request": {"url": "https://example.com/", "cookies": [{"name": "session", "value": "abc123"}]},
+//!     "response": {"cookies": [{"name": "theme", "value": "dark", "path": "/", "httpOnly": true}]}
+//! }]}}"#;
+//!
+//! let entries = har::cookie_jars(log).unwrap();
+//!
+//! assert_eq!(entries[0].request.get("session").unwrap().value(), "abc123");
+//! assert_eq!(entries[0].response.as_header_values(), ["theme=dark; HttpOnly; Path=/"]);
+//! ```
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cookie::decode_value;
+use crate::cookie::parse::ParseSameSiteError;
+use crate::{Cookie, CookieJar, Expiration};
+
+/// A cookie object of a HAR request or response.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarCookie {
+    pub name: String,
+    /// The value as it appears in a header, percent-encoded when the
+    /// `percent-encoding` feature is enabled.
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// The expiry as an ISO 8601 date-time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_only: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub same_site: Option<String>,
+}
+
+/// The cookies of a single entry of a HAR log.
+#[derive(Debug, Clone)]
+pub struct HarEntryCookies {
+    /// The URL of the request.
+    pub url: String,
+    /// The cookies sent with the request.
+    pub request: CookieJar<'static>,
+    /// The cookies set by the response, as pending changes.
+    pub response: CookieJar<'static>,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HarError {
+    InvalidJson(String),
+    InvalidExpires(String),
+    InvalidValue(String),
+    ParseSameSiteError(ParseSameSiteError),
+}
+
+#[derive(Deserialize)]
+struct Har {
+    log: Log,
+}
+
+#[derive(Deserialize)]
+struct Log {
+    #[serde(default)]
+    entries: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    request: Message,
+    response: Message,
+}
+
+#[derive(Deserialize)]
+struct Message {
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    cookies: Vec<HarCookie>,
+}
+
+/// Extracts the request and response cookies of every entry of a HAR log.
+///
+/// The request cookies of an entry are the cookies of its `CookieJar`, the
+/// response cookies are added as pending changes, ready to be emitted as
+/// `Set-Cookie` headers.
+///
+/// # Arguments
+/// - `har`: The content of the HAR file.
+pub fn cookie_jars(har: &str) -> Result<Vec<HarEntryCookies>, HarError> {
+    let har: Har = serde_json::from_str(har).map_err(|e| HarError::InvalidJson(e.to_string()))?;
+
+    har.log
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let request = entry
+                .request
+                .cookies
+                .into_iter()
+                .map(Cookie::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut response = CookieJar::default();
+            for cookie in entry.response.cookies {
+                response.add(Cookie::try_from(cookie)?);
+            }
+
+            Ok(HarEntryCookies {
+                url: entry.request.url,
+                request: CookieJar::new(request),
+                response,
+            })
+        })
+        .collect()
+}
+
+impl From<&Cookie<'_>> for HarCookie {
+    /// Converts a cookie into a HAR cookie object.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::har::HarCookie;
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::builder("id", "1")
+    ///     .expires(Expiration::from_unix_timestamp(1761031680))
+    ///     .same_site(SameSite::Lax)
+    ///     .build();
+    /// let har = HarCookie::from(&cookie);
+    ///
+    /// assert_eq!(har.expires.as_deref(), Some("2025-10-21T07:28:00.000Z"));
+    /// assert_eq!(har.same_site.as_deref(), Some("Lax"));
+    /// ```
+    fn from(value: &Cookie<'_>) -> Self {
+        Self {
+            name: value.name().to_owned(),
+            value: value.encoded_value().into_owned(),
+            path: value.path().map(ToOwned::to_owned),
+            domain: value.domain().map(ToOwned::to_owned),
+            expires: value.expires().map(|e| e.to_iso8601()),
+            http_only: value.http_only(),
+            secure: value.secure(),
+            same_site: value.same_site().map(|s| s.to_string()),
+        }
+    }
+}
+
+impl TryFrom<HarCookie> for Cookie<'static> {
+    type Error = HarError;
+
+    /// Converts a HAR cookie object into a cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::har::HarCookie;
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let har = HarCookie {
+    ///     name: "id".into(),
+    ///     value: "1".into(),
+    ///     expires: Some("2025-10-21T07:28:00.000Z".into()),
+    ///     ..Default::default()
+    /// };
+    /// let cookie = Cookie::try_from(har).unwrap();
+    ///
+    /// assert_eq!(cookie.expires().unwrap().unix_timestamp(), 1761031680);
+    /// ```
+    fn try_from(value: HarCookie) -> Result<Self, Self::Error> {
+        let Some(decoded) = decode_value(&value.value).map(|v| v.into_owned()) else {
+            return Err(HarError::InvalidValue(value.value));
+        };
+        let mut cookie = Cookie::new(value.name, decoded);
+
+        if let Some(path) = value.path {
+            cookie.set_path(path);
+        }
+
+        if let Some(domain) = value.domain {
+            cookie.set_domain(domain);
+        }
+
+        if let Some(expires) = value.expires {
+            match Expiration::parse_iso8601(&expires) {
+                Some(expires) => cookie.set_expires(expires),
+                None => return Err(HarError::InvalidExpires(expires)),
+            }
+        }
+
+        if let Some(http_only) = value.http_only {
+            cookie.set_http_only(http_only);
+        }
+
+        if let Some(secure) = value.secure {
+            cookie.set_secure(secure);
+        }
+
+        if let Some(same_site) = value.same_site {
+            cookie.set_same_site(same_site.parse()?);
+        }
+
+        Ok(cookie)
+    }
+}
+
+impl From<ParseSameSiteError> for HarError {
+    fn from(value: ParseSameSiteError) -> Self {
+        Self::ParseSameSiteError(value)
+    }
+}
+
+impl Error for HarError {}
+
+impl fmt::Display for HarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HarError::InvalidJson(err) => write!(f, "invalid HAR: {err}"),
+            HarError::InvalidExpires(expires) => write!(f, "invalid expires: {expires}"),
+            HarError::InvalidValue(value) => {
+                write!(f, "value is not valid percent-encoded UTF-8: {value}")
+            }
+            HarError::ParseSameSiteError(err) => write!(f, "failed to parse sameSite: {err}"),
+        }
+    }
+}
@@ -1,13 +1,37 @@
-use std::borrow::Cow;
 use std::collections::BTreeSet;
 
 use crate::cookie::parse::ParseError;
-use crate::{Cookie, StringPrison};
+use crate::cookie::validate::ValidationError;
+use crate::Cookie;
 
 pub use self::changed::CookieChange;
+#[cfg(feature = "http")]
+pub use self::http::SetCookieError;
+#[cfg(feature = "private")]
+pub use self::private::{DecryptError, PrivateJar, PrivateJarMut};
+#[cfg(feature = "signed")]
+pub use self::signed::{SignedJar, SignedJarMut};
+pub use self::split::{split_cookie_header, SplitCookieHeader};
+#[cfg(feature = "tower")]
+pub use self::tower::{CookieLayer, CookieService, ResponseFuture, SharedJar};
 
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
 mod changed;
+#[cfg(feature = "http")]
+mod http;
 mod parse;
+#[cfg(feature = "private")]
+mod private;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "signed")]
+mod signed;
+mod split;
+#[cfg(feature = "tower")]
+mod tower;
 
 /// A container for managing HTTP cookies.
 ///
@@ -16,8 +40,7 @@ mod parse;
 /// into HTTP headers.
 #[derive(Debug, Clone, Default)]
 pub struct CookieJar<'a> {
-    prison: Option<StringPrison<'a>>,
-    cookie: BTreeSet<Cookie<'a>>,
+    cookie: Vec<Cookie<'a>>,
     changes: BTreeSet<CookieChange<'a>>,
 }
 
@@ -59,13 +82,34 @@ impl<'a> CookieJar<'a> {
     /// assert!(cookie.is_some());
     /// ```
     pub fn get(&self, name: &str) -> Option<&Cookie<'a>> {
-        match self.changes.iter().find(|c| c.name() == name) {
-            Some(change) => change.cookie(),
-            None => self.cookie.iter().find(|c| c.name() == name),
-        }
+        self.cookie().find(|c| c.name() == name)
     }
 
-    /// Adds a new cookie to the jar or replaces an existing one with the same name.
+    /// Retrieves every cookie with the given name.
+    ///
+    /// Browsers send a cookie once for every matching `Path` and `Domain` it
+    /// was set with, so a request may carry several cookies with the same
+    /// name. They are returned in the order they appear in the header, after
+    /// any pending additions with that name.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the cookies to retrieve.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let jar = CookieJar::parse("session=admin; user=bob; session=root").unwrap();
+    ///
+    /// let values: Vec<_> = jar.get_all("session").map(|c| c.value()).collect();
+    /// assert_eq!(values, ["admin", "root"]);
+    /// ```
+    pub fn get_all<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'s Cookie<'a>> + 's {
+        self.cookie().filter(move |c| c.name() == name)
+    }
+
+    /// Adds a new cookie to the jar or replaces an existing one with the same
+    /// name, `Domain` and `Path`.
     ///
     /// # Arguments
     /// - `cookie`: The cookie to add to the jar.
@@ -75,13 +119,17 @@ impl<'a> CookieJar<'a> {
     /// use cookie_rs::prelude::*;
     ///
     /// let mut jar = CookieJar::default();
-    /// jar.add(Cookie::new("session", "abc123"));
+    /// jar.add(Cookie::builder("session", "abc123").path("/").build());
+    /// jar.add(Cookie::builder("session", "def456").path("/admin").build());
+    ///
+    /// assert_eq!(jar.as_header_values().len(), 2);
     /// ```
     pub fn add<C: Into<Cookie<'a>>>(&mut self, cookie: C) {
         self.changes.replace(CookieChange::create(cookie.into()));
     }
 
-    /// Adds a new cookie to the jar or replaces an existing one with the same name.
+    /// Adds a new cookie to the jar or replaces an existing one with the same
+    /// name, `Domain` and `Path`.
     ///
     /// # Arguments
     /// - `cookie`: The cookie to add to the jar.
@@ -99,10 +147,16 @@ impl<'a> CookieJar<'a> {
         self.add(cookie);
     }
 
-    /// Removes a cookie from the jar by its name.
+    /// Removes a cookie from the jar.
+    ///
+    /// Browsers only delete a cookie when the removal has the same `Path` and
+    /// `Domain` it was set with, so `cookie` serves as a template: the emitted
+    /// header mirrors its attributes, with an empty value, `Max-Age=0` and an
+    /// `Expires` date in the past. A bare name removes a cookie set without
+    /// `Path` or `Domain`.
     ///
     /// # Arguments
-    /// - `name`: The name of the cookie to remove.
+    /// - `cookie`: The cookie to remove, or its name.
     ///
     /// # Example
     /// ```
@@ -111,13 +165,24 @@ impl<'a> CookieJar<'a> {
     /// let mut jar = CookieJar::default();
     /// jar.add(Cookie::new("session", "abc123"));
     /// jar.remove("session");
+    ///
+    /// jar.remove(Cookie::builder("token", "").path("/app").build());
+    /// assert!(jar.as_header_values().contains(
+    ///     &"token=; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=0; Path=/app".to_string()
+    /// ));
     /// ```
-    pub fn remove<N: Into<Cow<'a, str>>>(&mut self, name: N) {
-        self.changes.replace(CookieChange::remove(name.into()));
+    pub fn remove<C: Into<Cookie<'a>>>(&mut self, cookie: C) {
+        self.changes.replace(CookieChange::remove(cookie.into()));
     }
 
     /// Returns an iterator over all cookies currently stored in the jar.
     ///
+    /// Pending additions come first, followed by the cookies the jar was
+    /// created with. One of those is hidden only by an addition or removal
+    /// with the same name, `Domain` and `Path`; since cookies parsed from a
+    /// `Cookie` header carry neither attribute, only changes made without
+    /// `Domain` and `Path` hide them.
+    ///
     /// # Example
     /// ```
     /// use cookie_rs::prelude::*;
@@ -131,7 +196,7 @@ impl<'a> CookieJar<'a> {
         self.changes.iter().filter_map(|c| c.cookie()).chain(
             self.cookie
                 .iter()
-                .filter(|c| !self.changes.iter().any(|ch| ch.name() == c.name())),
+                .filter(|c| !self.changes.iter().any(|ch| ch.covers(c))),
         )
     }
 
@@ -198,6 +263,36 @@ impl<'a> CookieJar<'a> {
     pub fn as_header_values(&self) -> Vec<String> {
         self.changes.iter().map(|c| c.as_header_value()).collect()
     }
+
+    /// Converts all pending changes to `Set-Cookie` header values, validating
+    /// the added cookies first.
+    ///
+    /// Fails if an added cookie does not pass [`Cookie::validate`], for example
+    /// when it has an invalid name or violates the requirements of its `__Host-`
+    /// or `__Secure-` name prefix. Removals are validated the same way, since a
+    /// browser ignores a removal it would not accept as a cookie.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let mut jar = CookieJar::default();
+    /// jar.add(CookieBuilder::host_prefixed("session", "abc123").build());
+    /// assert!(jar.try_as_header_values().is_ok());
+    ///
+    /// jar.add(Cookie::new("__Host-id", "1"));
+    /// assert!(jar.try_as_header_values().is_err());
+    /// ```
+    pub fn try_as_header_values(&self) -> Result<Vec<String>, ValidationError> {
+        self.changes
+            .iter()
+            .try_for_each(|change| match change.cookie() {
+                Some(cookie) => cookie.validate(),
+                None => change.removal().map_or(Ok(()), |c| c.validate()),
+            })?;
+
+        Ok(self.as_header_values())
+    }
 }
 
 impl std::str::FromStr for CookieJar<'_> {
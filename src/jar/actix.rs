@@ -0,0 +1,114 @@
+use std::convert::Infallible;
+use std::future::{ready, Ready};
+
+use actix_web::dev::Payload;
+use actix_web::http::header::{COOKIE, SET_COOKIE};
+use actix_web::http::StatusCode;
+use actix_web::{FromRequest, HttpRequest, HttpResponseBuilder, ResponseError};
+
+use super::CookieJar;
+use crate::cookie::parse::ParseError;
+use crate::cookie::validate::ValidationError;
+
+impl FromRequest for CookieJar<'static> {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    /// Extracts the cookies of the request.
+    ///
+    /// All `Cookie` header lines are merged before parsing. Lines that are
+    /// not valid UTF-8 are skipped, and so are malformed pairs, see
+    /// [`CookieJar::parse_lossy`]. Use [`CookieJar::try_from_request`] to
+    /// reject them instead.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// async fn handler(jar: CookieJar<'static>) -> String {
+    ///     jar.get("user").map_or("anonymous", |c| c.value()).to_owned()
+    /// }
+    ///
+    /// let app = actix_web::App::new().route("/", actix_web::web::get().to(handler));
+    /// ```
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Ok(CookieJar::parse_lossy(join_cookie_lines(req))))
+    }
+}
+
+impl CookieJar<'static> {
+    /// Parses the `Cookie` headers of an actix-web request, failing on the
+    /// first malformed pair.
+    ///
+    /// The [`ParseError`] responds with `400 Bad Request` when returned from a
+    /// handler.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use actix_web::test::TestRequest;
+    ///
+    /// let req = TestRequest::default().insert_header(("cookie", "user=bob; junk")).to_http_request();
+    ///
+    /// assert!(CookieJar::try_from_request(&req).is_err());
+    /// ```
+    pub fn try_from_request(req: &HttpRequest) -> Result<Self, ParseError> {
+        Self::parse(join_cookie_lines(req))
+    }
+}
+
+fn join_cookie_lines(req: &HttpRequest) -> String {
+    req.headers()
+        .get_all(COOKIE)
+        .filter_map(|v| std::str::from_utf8(v.as_bytes()).ok())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl CookieJar<'_> {
+    /// Appends one `Set-Cookie` header per pending change to an actix-web response.
+    ///
+    /// The headers are written from the cookies of this crate, so attributes
+    /// that actix-web's cookie type lacks, such as `Partitioned`, are kept.
+    ///
+    /// The changes are validated first, see [`CookieJar::try_as_header_values`].
+    /// If a change is invalid, no header is written. The [`ValidationError`]
+    /// responds with `500 Internal Server Error` when returned from a handler.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use actix_web::HttpResponse;
+    ///
+    /// let mut jar = CookieJar::default();
+    /// jar.add(Cookie::builder("session", "abc123").partitioned(true).build());
+    ///
+    /// let mut response = HttpResponse::Ok();
+    /// jar.write_to_response(&mut response).unwrap();
+    ///
+    /// let response = response.finish();
+    /// assert_eq!(response.headers().get("set-cookie").unwrap(), "session=abc123; Partitioned");
+    /// ```
+    pub fn write_to_response(
+        &self,
+        response: &mut HttpResponseBuilder,
+    ) -> Result<(), ValidationError> {
+        for value in self.try_as_header_values()? {
+            response.append_header((SET_COOKIE, value));
+        }
+
+        Ok(())
+    }
+}
+
+impl ResponseError for ParseError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl ResponseError for ValidationError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
@@ -0,0 +1,88 @@
+use std::convert::Infallible;
+
+use axum_core::extract::FromRequestParts;
+use axum_core::response::{IntoResponse, IntoResponseParts, Response, ResponseParts};
+use http::header::SET_COOKIE;
+use http::request::Parts;
+use http::StatusCode;
+
+use super::CookieJar;
+use crate::cookie::parse::ParseError;
+use crate::cookie::validate::ValidationError;
+
+impl<S: Send + Sync> FromRequestParts<S> for CookieJar<'static> {
+    type Rejection = Infallible;
+
+    /// Extracts the cookies of the request, see [`CookieJar::from_headers`].
+    ///
+    /// Malformed pairs are skipped. To reject them, extract the `HeaderMap`
+    /// and call [`CookieJar::try_from_headers`] instead, its [`ParseError`]
+    /// responds with `400 Bad Request`.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// async fn handler(jar: CookieJar<'static>) -> String {
+    ///     jar.get("user").map_or("anonymous", |c| c.value()).to_owned()
+    /// }
+    ///
+    /// let app: axum::Router = axum::Router::new().route("/", axum::routing::get(handler));
+    /// ```
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(CookieJar::from_headers(&parts.headers))
+    }
+}
+
+impl IntoResponseParts for CookieJar<'_> {
+    type Error = (StatusCode, String);
+
+    /// Appends one `Set-Cookie` header per pending change.
+    ///
+    /// The changes are validated first, see [`CookieJar::try_as_header_values`].
+    /// If a change is invalid or cannot be converted to a header value, no
+    /// header is written and the response fails with
+    /// `500 Internal Server Error`.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// async fn login(mut jar: CookieJar<'static>) -> (CookieJar<'static>, &'static str) {
+    ///     jar.add(Cookie::builder("session", "abc123").http_only(true).build());
+    ///
+    ///     (jar, "logged in")
+    /// }
+    ///
+    /// let app: axum::Router = axum::Router::new().route("/login", axum::routing::post(login));
+    /// ```
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let values = self
+            .try_as_header_map_values()
+            .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?;
+
+        for value in values {
+            res.headers_mut().append(SET_COOKIE, value);
+        }
+
+        Ok(res)
+    }
+}
+
+impl IntoResponse for CookieJar<'_> {
+    fn into_response(self) -> Response {
+        (self, ()).into_response()
+    }
+}
+
+impl IntoResponse for ParseError {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+    }
+}
+
+impl IntoResponse for ValidationError {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+    }
+}
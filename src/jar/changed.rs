@@ -1,11 +1,22 @@
-use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::time::Duration;
 
-use crate::Cookie;
+use crate::{Cookie, Expiration};
 
+/// A pending addition or removal of a cookie in a [`CookieJar`](crate::CookieJar).
+///
+/// Changes are identified by the cookie's name, `Domain` and `Path`, so that
+/// cookies sharing a name but scoped to different paths or domains are tracked
+/// separately. Domains are compared case-insensitively, ignoring a leading dot.
+///
+/// A removal holds the cookie that is sent to expire the original one: it
+/// keeps the attributes of the template it was created from, with an empty
+/// value, `Max-Age=0` and an `Expires` date in the past.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CookieChange<'a> {
-    Create(Cookie<'a>),
-    Remove(Cow<'a, str>),
+    Create(#[cfg_attr(feature = "serde", serde(borrow))] Cookie<'a>),
+    Remove(#[cfg_attr(feature = "serde", serde(borrow))] Cookie<'a>),
 }
 
 impl<'a> CookieChange<'a> {
@@ -13,8 +24,12 @@ impl<'a> CookieChange<'a> {
         Self::Create(cookie)
     }
 
-    pub fn remove(name: Cow<'a, str>) -> Self {
-        Self::Remove(name)
+    pub fn remove(mut template: Cookie<'a>) -> Self {
+        template.set_value("");
+        template.set_expires(Expiration::UNIX_EPOCH);
+        template.set_max_age(Duration::ZERO);
+
+        Self::Remove(template)
     }
 
     pub fn cookie(&self) -> Option<&Cookie<'a>> {
@@ -24,13 +39,25 @@ impl<'a> CookieChange<'a> {
         }
     }
 
-    pub fn name(&self) -> &str {
+    pub fn removal(&self) -> Option<&Cookie<'a>> {
         match self {
-            Self::Create(cookie) => cookie.name(),
-            Self::Remove(name) => name.as_ref(),
+            Self::Create(_) => None,
+            Self::Remove(cookie) => Some(cookie),
         }
     }
 
+    pub fn name(&self) -> &str {
+        self.inner().name()
+    }
+
+    pub fn domain(&self) -> Option<&str> {
+        self.inner().domain()
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.inner().path()
+    }
+
     pub fn is_create(&self) -> bool {
         match self {
             Self::Create(_) => true,
@@ -49,17 +76,26 @@ impl<'a> CookieChange<'a> {
         }
     }
 
+    /// Returns `true` if this change has the same name, `Domain` and `Path`
+    /// as `cookie`, and so replaces or removes it.
+    pub(crate) fn covers(&self, cookie: &Cookie<'_>) -> bool {
+        self.inner().cmp_identity(cookie).is_eq()
+    }
+
     pub fn as_header_value(&self) -> String {
+        self.inner().to_string()
+    }
+
+    fn inner(&self) -> &Cookie<'a> {
         match self {
-            Self::Create(cookie) => cookie.to_string(),
-            Self::Remove(name) => format!("{name}=removed; Max-Age=0"),
+            Self::Create(cookie) | Self::Remove(cookie) => cookie,
         }
     }
 }
 
 impl PartialEq for CookieChange<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.name() == other.name()
+        self.cmp(other).is_eq()
     }
 }
 
@@ -72,7 +108,7 @@ impl PartialOrd for CookieChange<'_> {
 }
 
 impl Ord for CookieChange<'_> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.name().cmp(other.name())
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner().cmp_identity(other.inner())
     }
 }
@@ -0,0 +1,146 @@
+use std::error::Error;
+use std::fmt;
+
+use http::header::{InvalidHeaderValue, COOKIE, SET_COOKIE};
+use http::{HeaderMap, HeaderValue};
+
+use super::CookieJar;
+use crate::cookie::parse::ParseError;
+use crate::cookie::validate::ValidationError;
+
+/// An error writing the pending changes of a `CookieJar` as `Set-Cookie`
+/// headers.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetCookieError {
+    /// A change is not a valid cookie, see [`CookieJar::try_as_header_values`].
+    ValidationError(ValidationError),
+    /// A change cannot be represented as a header value.
+    InvalidHeaderValue,
+}
+
+impl CookieJar<'static> {
+    /// Parses the `Cookie` request headers of a `HeaderMap` into a `CookieJar`.
+    ///
+    /// HTTP/2 allows a client to split the `Cookie` header into several
+    /// lines, so all of them are merged before parsing. Lines that are not
+    /// valid UTF-8 are skipped, and so are malformed pairs, see
+    /// [`CookieJar::parse_lossy`]. Use [`CookieJar::try_from_headers`] to
+    /// reject them instead.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use http::header::COOKIE;
+    /// use http::HeaderMap;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.append(COOKIE, "session=abc123".parse().unwrap());
+    /// headers.append(COOKIE, "user=bob; junk; theme=dark".parse().unwrap());
+    ///
+    /// let jar = CookieJar::from_headers(&headers);
+    /// assert_eq!(jar.len(), 3);
+    /// ```
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self::parse_lossy(join_cookie_lines(headers))
+    }
+
+    /// Parses the `Cookie` request headers of a `HeaderMap` into a `CookieJar`,
+    /// failing on the first malformed pair.
+    ///
+    /// Lines that are not valid UTF-8 are still skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use http::header::COOKIE;
+    /// use http::HeaderMap;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.append(COOKIE, "user=bob; junk".parse().unwrap());
+    ///
+    /// assert!(CookieJar::try_from_headers(&headers).is_err());
+    /// ```
+    pub fn try_from_headers(headers: &HeaderMap) -> Result<Self, ParseError> {
+        Self::parse(join_cookie_lines(headers))
+    }
+}
+
+fn join_cookie_lines(headers: &HeaderMap) -> String {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|v| std::str::from_utf8(v.as_bytes()).ok())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl CookieJar<'_> {
+    /// Appends one `Set-Cookie` header per pending change to a `HeaderMap`.
+    ///
+    /// The changes are validated first, see [`CookieJar::try_as_header_values`].
+    /// Nothing is written if any change is invalid or cannot be converted to a
+    /// header value.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use http::header::SET_COOKIE;
+    /// use http::HeaderMap;
+    ///
+    /// let mut jar = CookieJar::default();
+    /// jar.add(Cookie::new("session", "abc123"));
+    /// jar.remove("user");
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// jar.write_to(&mut headers).unwrap();
+    ///
+    /// assert_eq!(headers.get_all(SET_COOKIE).iter().count(), 2);
+    /// ```
+    pub fn write_to(&self, headers: &mut HeaderMap) -> Result<(), SetCookieError> {
+        for value in self.try_as_header_map_values()? {
+            headers.append(SET_COOKIE, value);
+        }
+
+        Ok(())
+    }
+
+    /// Validates the pending changes and converts them to `Set-Cookie` header
+    /// values.
+    pub(crate) fn try_as_header_map_values(&self) -> Result<Vec<HeaderValue>, SetCookieError> {
+        self.try_as_header_values()?
+            .into_iter()
+            .map(|value| HeaderValue::try_from(value).map_err(Into::into))
+            .collect()
+    }
+}
+
+impl From<ValidationError> for SetCookieError {
+    fn from(value: ValidationError) -> Self {
+        Self::ValidationError(value)
+    }
+}
+
+impl From<InvalidHeaderValue> for SetCookieError {
+    fn from(_: InvalidHeaderValue) -> Self {
+        Self::InvalidHeaderValue
+    }
+}
+
+impl Error for SetCookieError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SetCookieError::ValidationError(err) => Some(err),
+            SetCookieError::InvalidHeaderValue => None,
+        }
+    }
+}
+
+impl fmt::Display for SetCookieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetCookieError::ValidationError(err) => write!(f, "{err}"),
+            SetCookieError::InvalidHeaderValue => write!(f, "invalid Set-Cookie header value."),
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
+use std::sync::Arc;
 
-use crate::cookie::parse::ParseError;
-use crate::{Cookie, StringPrison};
+use crate::cookie::parse::{ParseError, SpannedParseError};
+use crate::Cookie;
 
 use super::CookieJar;
 
@@ -25,7 +26,7 @@ impl<'a> CookieJar<'a> {
     /// assert_eq!(jar.get("session").unwrap().value(), "abc123");
     /// ```
     pub fn parse<V: Into<Cow<'a, str>>>(value: V) -> Result<Self, ParseError> {
-        Self::inner_parse(value.into(), false)
+        Self::inner_parse(value.into(), false, false).map_err(Into::into)
     }
 
     /// Parses a `Cookie` request header value into a `CookieJar` in strict mode.
@@ -47,28 +48,116 @@ impl<'a> CookieJar<'a> {
     /// assert_eq!(jar.get("user").unwrap().value(), "bob");
     /// ```
     pub fn parse_strict<V: Into<Cow<'a, str>>>(value: V) -> Result<Self, ParseError> {
-        Self::inner_parse(value.into(), true)
+        Self::inner_parse(value.into(), true, false).map_err(Into::into)
     }
 
-    fn inner_parse(value: Cow<'a, str>, strict: bool) -> Result<Self, ParseError> {
-        let prison = StringPrison::new(value);
+    /// Parses a `Cookie` request header value into a `CookieJar`, skipping
+    /// malformed pairs instead of failing.
+    ///
+    /// Pairs are parsed in lenient mode. A pair without a `=`, with an invalid
+    /// name or with a value that cannot be decoded is left out of the jar.
+    ///
+    /// # Arguments
+    /// - `value`: The `Cookie` header string.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let jar = CookieJar::parse_lossy("session=abc123; junk; user=bob");
+    ///
+    /// assert_eq!(jar.len(), 2);
+    /// assert_eq!(jar.get("user").unwrap().value(), "bob");
+    /// ```
+    pub fn parse_lossy<V: Into<Cow<'a, str>>>(value: V) -> Self {
+        Self::inner_parse(value.into(), false, true).unwrap_or_default()
+    }
+
+    /// Parses a `Cookie` request header like [`CookieJar::parse`], reporting
+    /// where parsing failed.
+    ///
+    /// The attribute index of the error is the index of the offending pair.
+    ///
+    /// # Arguments
+    /// - `value`: The `Cookie` header string.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let header = "session=abc123; user; theme=dark";
+    /// let err = CookieJar::parse_spanned(header).unwrap_err();
+    ///
+    /// assert_eq!(&header[err.span().range()], "user");
+    /// assert_eq!(err.span().attribute(), 1);
+    /// ```
+    pub fn parse_spanned<V: Into<Cow<'a, str>>>(value: V) -> Result<Self, SpannedParseError> {
+        Self::inner_parse(value.into(), false, false)
+    }
 
-        // SAFETY: prison and slice owned by the same struct
-        let str = unsafe { prison.get() };
+    /// Parses a `Cookie` request header like [`CookieJar::parse_strict`],
+    /// reporting where parsing failed.
+    ///
+    /// # Arguments
+    /// - `value`: The `Cookie` header string.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let header = "session=abc123; user id=bob";
+    /// let err = CookieJar::parse_strict_spanned(header).unwrap_err();
+    ///
+    /// assert_eq!(&header[err.span().range()], "user id");
+    /// ```
+    pub fn parse_strict_spanned<V: Into<Cow<'a, str>>>(
+        value: V,
+    ) -> Result<Self, SpannedParseError> {
+        Self::inner_parse(value.into(), true, false)
+    }
 
-        let mut jar = parse_jar(str, strict)?;
-        jar.prison = Some(prison);
+    fn inner_parse(
+        value: Cow<'a, str>,
+        strict: bool,
+        skip_invalid: bool,
+    ) -> Result<Self, SpannedParseError> {
+        match value {
+            Cow::Borrowed(str) => parse_jar(str, strict, skip_invalid),
+            Cow::Owned(string) => {
+                let buffer = Arc::from(string);
+                let jar = parse_jar(&buffer, strict, skip_invalid)?;
 
-        Ok(jar)
+                Ok(CookieJar {
+                    cookie: jar.cookie.into_iter().map(|c| c.share(&buffer)).collect(),
+                    ..Default::default()
+                })
+            }
+        }
     }
 }
 
-fn parse_jar(str: &str, strict: bool) -> Result<CookieJar<'_>, ParseError> {
+fn parse_jar(
+    str: &str,
+    strict: bool,
+    skip_invalid: bool,
+) -> Result<CookieJar<'_>, SpannedParseError> {
     let mut jar = CookieJar::default();
-    let cookie = str.split(';').map(|p| p.trim()).filter(|p| !p.is_empty());
 
-    for pair in cookie {
-        jar.cookie.insert(Cookie::inner_parse(pair.into(), strict)?);
+    for (index, pair) in str.split(';').enumerate() {
+        let pair = pair.trim();
+
+        if pair.is_empty() {
+            continue;
+        }
+
+        let offset = pair.as_ptr() as usize - str.as_ptr() as usize;
+        let cookie = match Cookie::inner_parse(pair.into(), strict, false) {
+            Ok(cookie) => cookie,
+            Err(_) if skip_invalid => continue,
+            Err(err) => return Err(err.shift(offset, index)),
+        };
+
+        jar.cookie.push(cookie);
     }
 
     Ok(jar)
@@ -0,0 +1,278 @@
+use std::error::Error;
+use std::fmt;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::{Cookie, CookieJar, Key, Keys};
+
+/// Length of an AES-GCM nonce.
+const NONCE_LEN: usize = 12;
+
+/// Length of an AES-GCM authentication tag.
+const TAG_LEN: usize = 16;
+
+/// A read-only view over a `CookieJar` that decrypts private cookies.
+///
+/// Obtained through [`CookieJar::private`].
+pub struct PrivateJar<'j, 'a> {
+    jar: &'j CookieJar<'a>,
+    keys: &'j [Key],
+}
+
+/// A mutable view over a `CookieJar` that encrypts and decrypts cookies.
+///
+/// Obtained through [`CookieJar::private_mut`].
+pub struct PrivateJarMut<'j, 'a> {
+    jar: &'j mut CookieJar<'a>,
+    keys: &'j [Key],
+    reissue: bool,
+}
+
+/// The reason a private cookie could not be retrieved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecryptError {
+    Missing,
+    InvalidBase64,
+    TooShort,
+    Tampered,
+    InvalidUtf8,
+}
+
+impl<'a> CookieJar<'a> {
+    /// Returns a read-only view that decrypts cookies encrypted with any of `keys`.
+    ///
+    /// `keys` is either a single [`Key`] or a [`KeyRing`](crate::KeyRing).
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let jar = CookieJar::parse("user=42").unwrap();
+    ///
+    /// assert!(jar.private(&key).get("user").is_none());
+    /// ```
+    pub fn private<'j, K: Keys + ?Sized>(&'j self, keys: &'j K) -> PrivateJar<'j, 'a> {
+        PrivateJar {
+            jar: self,
+            keys: keys.keys(),
+        }
+    }
+
+    /// Returns a mutable view that encrypts cookies with the active key of `keys`
+    /// and decrypts them with any of `keys`.
+    ///
+    /// `keys` is either a single [`Key`] or a [`KeyRing`](crate::KeyRing).
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let mut jar = CookieJar::default();
+    /// jar.private_mut(&key).add(Cookie::new("user", "42"));
+    ///
+    /// assert!(!jar.get("user").unwrap().value().contains("42"));
+    /// assert_eq!(jar.private(&key).get("user").unwrap().value(), "42");
+    /// ```
+    pub fn private_mut<'j, K: Keys + ?Sized>(&'j mut self, keys: &'j K) -> PrivateJarMut<'j, 'a> {
+        PrivateJarMut {
+            jar: self,
+            keys: keys.keys(),
+            reissue: keys.reissue(),
+        }
+    }
+}
+
+impl<'a> PrivateJar<'_, 'a> {
+    /// Retrieves a cookie by its name and decrypts its value.
+    ///
+    /// Returns `None` if the cookie is missing or cannot be decrypted.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let mut jar = CookieJar::default();
+    /// jar.private_mut(&key).add(Cookie::new("user", "42"));
+    ///
+    /// assert_eq!(jar.private(&key).get("user").unwrap().value(), "42");
+    /// ```
+    pub fn get(&self, name: &str) -> Option<Cookie<'a>> {
+        self.try_get(name).ok()
+    }
+
+    /// Retrieves a cookie by its name and decrypts its value, reporting why it failed.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::error::DecryptError;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let jar = CookieJar::parse("user=AAAA").unwrap();
+    ///
+    /// assert_eq!(jar.private(&key).try_get("user"), Err(DecryptError::TooShort));
+    /// assert_eq!(jar.private(&key).try_get("other"), Err(DecryptError::Missing));
+    /// ```
+    pub fn try_get(&self, name: &str) -> Result<Cookie<'a>, DecryptError> {
+        let cookie = self.jar.get(name).ok_or(DecryptError::Missing)?;
+
+        decrypt(self.keys, cookie).map(|(cookie, _)| cookie)
+    }
+}
+
+impl<'a> PrivateJarMut<'_, 'a> {
+    /// Retrieves a cookie by its name and decrypts its value.
+    ///
+    /// See [`PrivateJarMut::try_get`].
+    pub fn get(&mut self, name: &str) -> Option<Cookie<'a>> {
+        self.try_get(name).ok()
+    }
+
+    /// Retrieves a cookie by its name and decrypts its value, reporting why it failed.
+    ///
+    /// If the cookie was encrypted with a retired key and the [`KeyRing`](crate::KeyRing)
+    /// asks for it, the cookie is re-encrypted with the active key and added back to
+    /// the jar. See [`PrivateJar::try_get`].
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::{Key, KeyRing};
+    ///
+    /// let old = Key::try_from(&[1; 64][..]).unwrap();
+    /// let new = Key::try_from(&[2; 64][..]).unwrap();
+    ///
+    /// let mut jar = CookieJar::default();
+    /// jar.private_mut(&old).add(Cookie::new("user", "42"));
+    ///
+    /// let ring = KeyRing::new(new.clone()).with_retired(old).with_reissue(true);
+    /// assert_eq!(jar.private_mut(&ring).try_get("user").unwrap().value(), "42");
+    /// assert_eq!(jar.private(&new).try_get("user").unwrap().value(), "42");
+    /// ```
+    pub fn try_get(&mut self, name: &str) -> Result<Cookie<'a>, DecryptError> {
+        let cookie = self.jar.get(name).ok_or(DecryptError::Missing)?;
+        let (cookie, retired) = decrypt(self.keys, cookie)?;
+
+        if retired && self.reissue {
+            self.add(cookie.clone());
+        }
+
+        Ok(cookie)
+    }
+
+    /// Encrypts a cookie and adds it to the underlying jar.
+    ///
+    /// The addition is tracked like [`CookieJar::add`].
+    ///
+    /// # Arguments
+    /// - `cookie`: The cookie to encrypt and add.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let mut jar = CookieJar::default();
+    /// jar.private_mut(&key).add(Cookie::new("user", "42"));
+    ///
+    /// assert_eq!(jar.as_header_values().len(), 1);
+    /// ```
+    pub fn add<C: Into<Cookie<'a>>>(&mut self, cookie: C) {
+        let mut cookie = cookie.into();
+        encrypt(&self.keys[0], &mut cookie);
+
+        self.jar.add(cookie);
+    }
+
+    /// Removes a cookie from the underlying jar.
+    ///
+    /// See [`CookieJar::remove`].
+    pub fn remove<C: Into<Cookie<'a>>>(&mut self, cookie: C) {
+        self.jar.remove(cookie);
+    }
+}
+
+fn cipher(key: &Key) -> Aes256Gcm {
+    Aes256Gcm::new_from_slice(key.encryption()).expect("key is 32 bytes long")
+}
+
+fn encrypt(key: &Key, cookie: &mut Cookie<'_>) {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+    // the name is bound as associated data so the value cannot be moved to another cookie
+    let payload = Payload {
+        msg: cookie.value().as_bytes(),
+        aad: cookie.name().as_bytes(),
+    };
+
+    let ciphertext = cipher(key)
+        .encrypt(&nonce, payload)
+        .expect("plaintext fits into AES-GCM limits");
+
+    let mut sealed = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+
+    cookie.set_value(STANDARD.encode(sealed));
+}
+
+/// Decrypts the cookie with each key, also reporting whether a retired key matched.
+fn decrypt<'a>(keys: &[Key], cookie: &Cookie<'a>) -> Result<(Cookie<'a>, bool), DecryptError> {
+    let sealed = STANDARD
+        .decode(cookie.value())
+        .map_err(|_| DecryptError::InvalidBase64)?;
+
+    if sealed.len() < NONCE_LEN + TAG_LEN {
+        return Err(DecryptError::TooShort);
+    }
+
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+
+    let (index, plaintext) = keys
+        .iter()
+        .enumerate()
+        .find_map(|(i, key)| {
+            let payload = Payload {
+                msg: ciphertext,
+                aad: cookie.name().as_bytes(),
+            };
+
+            cipher(key)
+                .decrypt(Nonce::from_slice(nonce), payload)
+                .ok()
+                .map(|plaintext| (i, plaintext))
+        })
+        .ok_or(DecryptError::Tampered)?;
+
+    let value = String::from_utf8(plaintext).map_err(|_| DecryptError::InvalidUtf8)?;
+
+    let mut decrypted = cookie.clone();
+    decrypted.set_value(value);
+
+    Ok((decrypted, index > 0))
+}
+
+impl Error for DecryptError {}
+
+impl fmt::Display for DecryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecryptError::Missing => write!(f, "cookie not found."),
+            DecryptError::InvalidBase64 => write!(f, "value is not valid base64."),
+            DecryptError::TooShort => write!(f, "value is too short to be encrypted."),
+            DecryptError::Tampered => write!(f, "value failed authentication."),
+            DecryptError::InvalidUtf8 => write!(f, "decrypted value is not valid UTF-8."),
+        }
+    }
+}
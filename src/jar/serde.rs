@@ -0,0 +1,35 @@
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{CookieChange, CookieJar};
+use crate::Cookie;
+
+/// The serialized form of a [`CookieJar`]: the request cookies and the pending changes.
+#[derive(Deserialize)]
+#[serde(rename = "CookieJar")]
+struct CookieJarRepr<'a> {
+    #[serde(borrow, default)]
+    cookies: Vec<Cookie<'a>>,
+    #[serde(borrow, default)]
+    changes: Vec<CookieChange<'a>>,
+}
+
+impl Serialize for CookieJar<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut jar = serializer.serialize_struct("CookieJar", 2)?;
+        jar.serialize_field("cookies", &self.cookie)?;
+        jar.serialize_field("changes", &self.changes)?;
+        jar.end()
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for CookieJar<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CookieJarRepr::deserialize(deserializer)?;
+
+        Ok(Self {
+            cookie: repr.cookies,
+            changes: repr.changes.into_iter().collect(),
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{Cookie, CookieJar, Key, Keys};
+
+/// Length of a base64-encoded HMAC-SHA256 tag.
+const BASE64_MAC_LEN: usize = 44;
+
+/// A read-only view over a `CookieJar` that verifies signed cookies.
+///
+/// Obtained through [`CookieJar::signed`].
+pub struct SignedJar<'j, 'a> {
+    jar: &'j CookieJar<'a>,
+    keys: &'j [Key],
+}
+
+/// A mutable view over a `CookieJar` that signs and verifies cookies.
+///
+/// Obtained through [`CookieJar::signed_mut`].
+pub struct SignedJarMut<'j, 'a> {
+    jar: &'j mut CookieJar<'a>,
+    keys: &'j [Key],
+    reissue: bool,
+}
+
+impl<'a> CookieJar<'a> {
+    /// Returns a read-only view that verifies cookies signed with any of `keys`.
+    ///
+    /// `keys` is either a single [`Key`] or a [`KeyRing`](crate::KeyRing).
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let jar = CookieJar::parse("session=abc123").unwrap();
+    ///
+    /// assert!(jar.signed(&key).get("session").is_none());
+    /// ```
+    pub fn signed<'j, K: Keys + ?Sized>(&'j self, keys: &'j K) -> SignedJar<'j, 'a> {
+        SignedJar {
+            jar: self,
+            keys: keys.keys(),
+        }
+    }
+
+    /// Returns a mutable view that signs cookies with the active key of `keys`
+    /// and verifies them with any of `keys`.
+    ///
+    /// `keys` is either a single [`Key`] or a [`KeyRing`](crate::KeyRing).
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let mut jar = CookieJar::default();
+    /// jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+    ///
+    /// assert_ne!(jar.get("session").unwrap().value(), "abc123");
+    /// assert_eq!(jar.signed(&key).get("session").unwrap().value(), "abc123");
+    /// ```
+    pub fn signed_mut<'j, K: Keys + ?Sized>(&'j mut self, keys: &'j K) -> SignedJarMut<'j, 'a> {
+        SignedJarMut {
+            jar: self,
+            keys: keys.keys(),
+            reissue: keys.reissue(),
+        }
+    }
+}
+
+impl<'a> SignedJar<'_, 'a> {
+    /// Retrieves a cookie by its name and verifies its signature.
+    ///
+    /// Returns the cookie with the signature stripped from its value, or
+    /// `None` if the cookie is missing or fails verification.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let mut jar = CookieJar::default();
+    /// jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+    ///
+    /// assert_eq!(jar.signed(&key).get("session").unwrap().value(), "abc123");
+    /// ```
+    pub fn get(&self, name: &str) -> Option<Cookie<'a>> {
+        verify(self.keys, self.jar.get(name)?).map(|(cookie, _)| cookie)
+    }
+}
+
+impl<'a> SignedJarMut<'_, 'a> {
+    /// Retrieves a cookie by its name and verifies its signature.
+    ///
+    /// If the cookie was signed with a retired key and the [`KeyRing`](crate::KeyRing)
+    /// asks for it, the cookie is re-signed with the active key and added back to
+    /// the jar. See [`SignedJar::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::{Key, KeyRing};
+    ///
+    /// let old = Key::try_from(&[1; 64][..]).unwrap();
+    /// let new = Key::try_from(&[2; 64][..]).unwrap();
+    ///
+    /// let mut jar = CookieJar::default();
+    /// jar.signed_mut(&old).add(Cookie::new("session", "abc123"));
+    ///
+    /// let ring = KeyRing::new(new.clone()).with_retired(old).with_reissue(true);
+    /// assert_eq!(jar.signed_mut(&ring).get("session").unwrap().value(), "abc123");
+    /// assert_eq!(jar.signed(&new).get("session").unwrap().value(), "abc123");
+    /// ```
+    pub fn get(&mut self, name: &str) -> Option<Cookie<'a>> {
+        let (cookie, retired) = verify(self.keys, self.jar.get(name)?)?;
+
+        if retired && self.reissue {
+            self.add(cookie.clone());
+        }
+
+        Some(cookie)
+    }
+
+    /// Signs a cookie and adds it to the underlying jar.
+    ///
+    /// The addition is tracked like [`CookieJar::add`].
+    ///
+    /// # Arguments
+    /// - `cookie`: The cookie to sign and add.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::Key;
+    ///
+    /// let key = Key::try_from(&[1; 64][..]).unwrap();
+    /// let mut jar = CookieJar::default();
+    /// jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+    ///
+    /// assert_eq!(jar.as_header_values().len(), 1);
+    /// ```
+    pub fn add<C: Into<Cookie<'a>>>(&mut self, cookie: C) {
+        let mut cookie = cookie.into();
+        sign(&self.keys[0], &mut cookie);
+
+        self.jar.add(cookie);
+    }
+
+    /// Removes a cookie from the underlying jar.
+    ///
+    /// See [`CookieJar::remove`].
+    pub fn remove<C: Into<Cookie<'a>>>(&mut self, cookie: C) {
+        self.jar.remove(cookie);
+    }
+}
+
+fn mac(key: &Key, name: &str, value: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key.signing()).expect("any key length is valid");
+
+    // the name is length-prefixed so that `name` and `value` cannot be shifted into each other
+    mac.update(&(name.len() as u64).to_be_bytes());
+    mac.update(name.as_bytes());
+    mac.update(value.as_bytes());
+
+    mac
+}
+
+fn sign(key: &Key, cookie: &mut Cookie<'_>) {
+    let tag = mac(key, cookie.name(), cookie.value())
+        .finalize()
+        .into_bytes();
+    let value = format!("{}{}", STANDARD.encode(tag), cookie.value());
+
+    cookie.set_value(value);
+}
+
+/// Verifies the cookie against each key, also reporting whether a retired key matched.
+fn verify<'a>(keys: &[Key], cookie: &Cookie<'a>) -> Option<(Cookie<'a>, bool)> {
+    let value = cookie.value();
+
+    if !value.is_char_boundary(BASE64_MAC_LEN) {
+        return None;
+    }
+
+    let (tag, value) = value.split_at(BASE64_MAC_LEN);
+    let tag = STANDARD.decode(tag).ok()?;
+
+    let index = keys
+        .iter()
+        .position(|key| mac(key, cookie.name(), value).verify_slice(&tag).is_ok())?;
+
+    let mut verified = cookie.clone();
+    verified.set_value(value.to_owned());
+
+    Some((verified, index > 0))
+}
@@ -0,0 +1,87 @@
+use std::iter::FusedIterator;
+
+use super::CookieJar;
+
+/// A lazy iterator over the `name=value` pairs of a `Cookie` request header.
+///
+/// Created by [`split_cookie_header`].
+#[derive(Debug, Clone)]
+pub struct SplitCookieHeader<'h> {
+    rest: &'h str,
+}
+
+/// Splits a `Cookie` request header value into `(name, raw_value)` pairs.
+///
+/// Pairs are yielded lazily in header order, borrowing from `header` without
+/// allocating. Names and values are trimmed, but values are neither
+/// percent-decoded nor unquoted. Empty pairs, pairs without `=` and pairs with
+/// an empty name are skipped.
+///
+/// # Arguments
+/// - `header`: The `Cookie` header string.
+///
+/// # Example
+/// ```
+/// use cookie_rs::jar::split_cookie_header;
+///
+/// let mut pairs = split_cookie_header("session=abc123; theme=dark%20blue;; broken");
+///
+/// assert_eq!(pairs.next(), Some(("session", "abc123")));
+/// assert_eq!(pairs.next(), Some(("theme", "dark%20blue")));
+/// assert_eq!(pairs.next(), None);
+/// ```
+pub fn split_cookie_header(header: &str) -> SplitCookieHeader<'_> {
+    SplitCookieHeader { rest: header }
+}
+
+impl<'h> Iterator for SplitCookieHeader<'h> {
+    type Item = (&'h str, &'h str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.rest.is_empty() {
+            let (pair, rest) = self.rest.split_once(';').unwrap_or((self.rest, ""));
+            self.rest = rest;
+
+            let Some((name, value)) = pair.split_once('=') else {
+                continue;
+            };
+
+            let name = name.trim();
+
+            if !name.is_empty() {
+                return Some((name, value.trim()));
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for SplitCookieHeader<'_> {}
+
+impl CookieJar<'_> {
+    /// Finds the raw value of the first cookie named `name` in a `Cookie` header.
+    ///
+    /// This is a shortcut over [`split_cookie_header`] for when only one cookie
+    /// is needed; it neither allocates nor builds a jar. The value is returned
+    /// as it appears in the header, without percent-decoding.
+    ///
+    /// # Arguments
+    /// - `header`: The `Cookie` header string.
+    /// - `name`: The name of the cookie to find.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let header = "theme=dark; session=abc123";
+    ///
+    /// assert_eq!(CookieJar::find_in_header(header, "session"), Some("abc123"));
+    /// assert_eq!(CookieJar::find_in_header(header, "user"), None);
+    /// ```
+    pub fn find_in_header<'h>(header: &'h str, name: &str) -> Option<&'h str> {
+        split_cookie_header(header)
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| value)
+    }
+}
@@ -0,0 +1,175 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll};
+
+use http::header::SET_COOKIE;
+use http::{Request, Response, StatusCode};
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use super::CookieJar;
+
+/// A `tower` layer that manages a [`CookieJar`] for every request.
+///
+/// The `Cookie` headers of each request are parsed once into a [`SharedJar`]
+/// stored in the request extensions. Once the inner service responds, the
+/// pending changes of the jar are appended to the response as `Set-Cookie`
+/// headers.
+///
+/// Malformed pairs in the `Cookie` headers are left out of the jar, see
+/// [`CookieJar::from_headers`]. The [`ParseError`](crate::error::ParseError)
+/// of the first one is inserted into the request extensions next to it, so
+/// handlers can still reject the request.
+///
+/// The changes are validated before they are written, see
+/// [`CookieJar::try_as_header_values`]. If a change is invalid or cannot be
+/// converted to a header value, the response of the inner service is replaced
+/// by an empty `500 Internal Server Error` response without any `Set-Cookie`
+/// header, and the [`SetCookieError`](crate::error::SetCookieError) is
+/// inserted into its extensions.
+///
+/// # Example
+/// ```
+/// use cookie_rs::jar::{CookieLayer, SharedJar};
+/// use cookie_rs::prelude::*;
+/// use axum::{routing::get, Extension, Router};
+///
+/// async fn handler(Extension(jar): Extension<SharedJar>) -> &'static str {
+///     jar.lock().add(Cookie::new("visited", "1"));
+///
+///     "hello"
+/// }
+///
+/// let app: Router = Router::new().route("/", get(handler)).layer(CookieLayer::new());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CookieLayer;
+
+/// The service produced by [`CookieLayer`].
+#[derive(Debug, Clone)]
+pub struct CookieService<S> {
+    inner: S,
+}
+
+/// A handle to the `CookieJar` of the current request.
+///
+/// Cloning the handle shares the same jar.
+#[derive(Debug, Clone, Default)]
+pub struct SharedJar(Arc<Mutex<CookieJar<'static>>>);
+
+pin_project! {
+    /// The response future of [`CookieService`].
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: F,
+        jar: SharedJar,
+    }
+}
+
+impl CookieLayer {
+    /// Creates a new `CookieLayer`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SharedJar {
+    /// Creates a new `SharedJar` wrapping `jar`.
+    ///
+    /// # Arguments
+    /// - `jar`: The jar to share.
+    pub fn new(jar: CookieJar<'static>) -> Self {
+        Self(Arc::new(Mutex::new(jar)))
+    }
+
+    /// Locks the jar for reading or modification.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::jar::SharedJar;
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let jar = SharedJar::new(CookieJar::parse("session=abc123").unwrap());
+    /// jar.lock().remove("session");
+    ///
+    /// assert!(jar.lock().get("session").is_none());
+    /// ```
+    pub fn lock(&self) -> MutexGuard<'_, CookieJar<'static>> {
+        // the jar stays consistent even if a holder of the lock panicked
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<S> Layer<S> for CookieLayer {
+    type Service = CookieService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CookieService { inner }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for CookieService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    ResBody: Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let jar = match CookieJar::try_from_headers(req.headers()) {
+            Ok(jar) => SharedJar::new(jar),
+            Err(err) => {
+                req.extensions_mut().insert(err);
+                SharedJar::new(CookieJar::from_headers(req.headers()))
+            }
+        };
+        req.extensions_mut().insert(jar.clone());
+
+        ResponseFuture {
+            inner: self.inner.call(req),
+            jar,
+        }
+    }
+}
+
+impl<F, ResBody, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<ResBody>, E>>,
+    ResBody: Default,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut response = std::task::ready!(this.inner.poll(cx))?;
+
+        match this.jar.lock().try_as_header_map_values() {
+            Ok(values) => {
+                for value in values {
+                    response.headers_mut().append(SET_COOKIE, value);
+                }
+            }
+            Err(err) => {
+                response = internal_error();
+                response.extensions_mut().insert(err);
+            }
+        }
+
+        Poll::Ready(Ok(response))
+    }
+}
+
+fn internal_error<B: Default>() -> Response<B> {
+    let mut response = Response::new(B::default());
+    *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+
+    response
+}
@@ -0,0 +1,214 @@
+use std::error::Error;
+use std::fmt;
+
+/// The minimum length of the master key material accepted by [`Key`].
+const KEY_LEN: usize = 64;
+
+/// A cryptographic master key for signed and private cookies.
+///
+/// The first 32 bytes of the master key are used for signing and the next
+/// 32 bytes for encryption.
+///
+/// # Example
+/// ```
+/// use cookie_rs::Key;
+///
+/// let key = Key::try_from(&[7; 64][..]).unwrap();
+/// assert!(Key::try_from(&[7; 16][..]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct Key([u8; KEY_LEN]);
+
+/// A set of keys with one active key and any number of retired keys.
+///
+/// Cookies are always signed or encrypted with the active key, while reads
+/// are validated against every key in the ring. This allows rotating secrets
+/// without invalidating cookies issued under a previous key.
+///
+/// # Example
+/// ```
+/// use cookie_rs::{Key, KeyRing};
+///
+/// let old = Key::try_from(&[1; 64][..]).unwrap();
+/// let new = Key::try_from(&[2; 64][..]).unwrap();
+///
+/// let ring = KeyRing::new(new).with_retired(old).with_reissue(true);
+/// assert_eq!(ring.retired().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyRing {
+    keys: Vec<Key>,
+    reissue: bool,
+}
+
+/// A source of keys for signed and private cookies.
+///
+/// Implemented by [`Key`] and [`KeyRing`]. The trait is sealed, so that
+/// [`Keys::keys`] is guaranteed to contain at least the active key.
+///
+/// ```compile_fail
+/// use cookie_rs::{Key, Keys};
+///
+/// struct Empty;
+///
+/// impl Keys for Empty {
+///     fn keys(&self) -> &[Key] {
+///         &[]
+///     }
+/// }
+/// ```
+pub trait Keys: sealed::Sealed {
+    /// Returns all keys, the active key first. Never empty.
+    fn keys(&self) -> &[Key];
+
+    /// Returns `true` if cookies validated with a retired key should be re-issued
+    /// with the active key.
+    fn reissue(&self) -> bool {
+        false
+    }
+}
+
+/// An error returned when constructing a [`Key`] from too little key material.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyError {
+    TooShort(usize),
+}
+
+impl Key {
+    #[cfg(feature = "signed")]
+    pub(crate) fn signing(&self) -> &[u8] {
+        &self.0[..32]
+    }
+
+    #[cfg(feature = "private")]
+    pub(crate) fn encryption(&self) -> &[u8] {
+        &self.0[32..]
+    }
+}
+
+impl KeyRing {
+    /// Creates a new `KeyRing` with the given active key and no retired keys.
+    ///
+    /// # Arguments
+    /// - `active`: The key used to sign and encrypt new cookies.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::{Key, KeyRing};
+    ///
+    /// let ring = KeyRing::new(Key::try_from(&[1; 64][..]).unwrap());
+    /// assert!(ring.retired().is_empty());
+    /// ```
+    pub fn new(active: Key) -> Self {
+        Self {
+            keys: vec![active],
+            reissue: false,
+        }
+    }
+
+    /// Adds a retired key that is still accepted when reading cookies.
+    ///
+    /// # Arguments
+    /// - `key`: The retired key.
+    pub fn add_retired(&mut self, key: Key) {
+        self.keys.push(key);
+    }
+
+    /// Sets whether cookies validated with a retired key are re-issued with the active key.
+    ///
+    /// # Arguments
+    /// - `reissue`: Whether to re-issue such cookies.
+    pub fn set_reissue(&mut self, reissue: bool) {
+        self.reissue = reissue;
+    }
+
+    /// Adds a retired key that is still accepted when reading cookies.
+    ///
+    /// # Arguments
+    /// - `key`: The retired key.
+    pub fn with_retired(mut self, key: Key) -> Self {
+        self.add_retired(key);
+
+        self
+    }
+
+    /// Sets whether cookies validated with a retired key are re-issued with the active key.
+    ///
+    /// Re-issuing happens on reads through the mutable signed and private views
+    /// and goes through [`CookieJar::add`](crate::CookieJar::add), so it appears
+    /// in `changes()`.
+    ///
+    /// # Arguments
+    /// - `reissue`: Whether to re-issue such cookies.
+    pub fn with_reissue(mut self, reissue: bool) -> Self {
+        self.set_reissue(reissue);
+
+        self
+    }
+
+    /// Returns the active key.
+    pub fn active(&self) -> &Key {
+        &self.keys[0]
+    }
+
+    /// Returns the retired keys.
+    pub fn retired(&self) -> &[Key] {
+        &self.keys[1..]
+    }
+}
+
+impl Keys for Key {
+    fn keys(&self) -> &[Key] {
+        std::slice::from_ref(self)
+    }
+}
+
+impl Keys for KeyRing {
+    fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    fn reissue(&self) -> bool {
+        self.reissue
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Key {}
+    impl Sealed for super::KeyRing {}
+}
+
+impl TryFrom<&[u8]> for Key {
+    type Error = KeyError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < KEY_LEN {
+            return Err(KeyError::TooShort(value.len()));
+        }
+
+        let mut master = [0; KEY_LEN];
+        master.copy_from_slice(&value[..KEY_LEN]);
+
+        Ok(Self(master))
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Key").finish_non_exhaustive()
+    }
+}
+
+impl Error for KeyError {}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyError::TooShort(len) => {
+                write!(f, "key material too short: {len} bytes, expected {KEY_LEN}")
+            }
+        }
+    }
+}
@@ -2,22 +2,49 @@
 
 pub use crate::cookie::Cookie;
 pub use crate::cookie::CookieBuilder;
+pub use crate::cookie::Expiration;
 pub use crate::jar::CookieJar;
+#[cfg(any(feature = "signed", feature = "private"))]
+pub use crate::key::{Key, KeyRing, Keys};
+pub use crate::store::CookieStore;
 
-pub(crate) use prison::StringPrison;
+pub(crate) use buffer::CookieStr;
 
-mod prison;
+mod buffer;
+#[cfg(any(feature = "signed", feature = "private"))]
+mod key;
 
 pub mod cookie;
+#[cfg(feature = "har")]
+pub mod har;
 pub mod jar;
+#[cfg(feature = "psl")]
+pub mod psl;
+pub mod store;
 
 pub mod error {
     pub use crate::cookie::parse::error::*;
+    pub use crate::cookie::prefix::PrefixError;
+    pub use crate::cookie::validate::ValidationError;
+    #[cfg(feature = "actix")]
+    pub use crate::cookie::ActixCookieError;
+    #[cfg(feature = "har")]
+    pub use crate::har::HarError;
+    #[cfg(feature = "private")]
+    pub use crate::jar::DecryptError;
+    #[cfg(feature = "http")]
+    pub use crate::jar::SetCookieError;
+    #[cfg(any(feature = "signed", feature = "private"))]
+    pub use crate::key::KeyError;
+    pub use crate::store::error::*;
 }
 
 pub mod prelude {
     pub use crate::cookie::Cookie;
     pub use crate::cookie::CookieBuilder;
+    pub use crate::cookie::Expiration;
+    pub use crate::cookie::Priority;
     pub use crate::cookie::SameSite;
     pub use crate::jar::CookieJar;
+    pub use crate::store::CookieStore;
 }
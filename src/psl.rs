@@ -0,0 +1,264 @@
+//! Public Suffix List support.
+//!
+//! A public suffix is a domain under which anyone can register names, such as
+//! `com` or `co.uk`. User agents must reject cookies whose `Domain` attribute
+//! is a public suffix, otherwise `Domain=co.uk` would be shared by every site
+//! registered under it.
+//!
+//! A copy of the [Public Suffix List](https://publicsuffix.org/) is bundled with
+//! the crate and used by [`is_public_suffix`]. A fresher copy of the list file can
+//! be loaded with [`PublicSuffixList::from_file`].
+//!
+//! # Example
+//! ```
+//! use cookie_rs::psl;
+//!
+//! assert!(psl::is_public_suffix("co.uk"));
+//! assert!(!psl::is_public_suffix("example.co.uk"));
+//! ```
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+static BUNDLED: OnceLock<PublicSuffixList> = OnceLock::new();
+
+/// A parsed Public Suffix List.
+#[derive(Debug, Clone, Default)]
+pub struct PublicSuffixList {
+    rules: HashSet<String>,
+    wildcards: HashSet<String>,
+    exceptions: HashSet<String>,
+}
+
+/// Returns `true` if `domain` is a public suffix according to the bundled list.
+///
+/// # Example
+/// ```
+/// use cookie_rs::psl;
+///
+/// assert!(psl::is_public_suffix("com"));
+/// assert!(psl::is_public_suffix("github.io"));
+/// assert!(!psl::is_public_suffix("example.com"));
+/// ```
+pub fn is_public_suffix(domain: &str) -> bool {
+    PublicSuffixList::bundled().is_public_suffix(domain)
+}
+
+impl PublicSuffixList {
+    /// Returns the list bundled with the crate.
+    pub fn bundled() -> &'static Self {
+        BUNDLED.get_or_init(|| Self::parse(include_str!("../data/public_suffix_list.dat")))
+    }
+
+    /// Parses a list in the `public_suffix_list.dat` format.
+    ///
+    /// Rules for internationalized domain names match both their Unicode and
+    /// their punycode (`xn--`) form.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::psl::PublicSuffixList;
+    ///
+    /// let list = PublicSuffixList::parse("// comment\ncom\n*.ck\n!www.ck\n");
+    ///
+    /// assert!(list.is_public_suffix("com"));
+    /// assert!(list.is_public_suffix("anything.ck"));
+    /// assert!(!list.is_public_suffix("www.ck"));
+    /// ```
+    pub fn parse(list: &str) -> Self {
+        let mut parsed = Self::default();
+
+        let rules = list
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .filter(|rule| !rule.starts_with("//"));
+
+        for rule in rules {
+            let rule = rule.to_lowercase();
+
+            let (set, rule) = if let Some(exception) = rule.strip_prefix('!') {
+                (&mut parsed.exceptions, exception)
+            } else if let Some(wildcard) = rule.strip_prefix("*.") {
+                (&mut parsed.wildcards, wildcard)
+            } else {
+                (&mut parsed.rules, rule.as_str())
+            };
+
+            // internationalized rules are listed in Unicode, while domains in
+            // cookies and urls are in their ASCII form
+            if let Some(ascii) = to_ascii(rule) {
+                set.insert(ascii);
+            }
+
+            set.insert(rule.to_owned());
+        }
+
+        parsed
+    }
+
+    /// Loads a local copy of the list file.
+    ///
+    /// # Arguments
+    /// - `path`: Path to a `public_suffix_list.dat` file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        std::fs::read_to_string(path).map(|list| Self::parse(&list))
+    }
+
+    /// Returns the public suffix of `domain`.
+    ///
+    /// Domains not covered by any rule fall back to their top-level label.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::psl::PublicSuffixList;
+    ///
+    /// let list = PublicSuffixList::bundled();
+    ///
+    /// assert_eq!(list.public_suffix("www.example.co.uk"), "co.uk");
+    /// assert_eq!(list.public_suffix("example.unknowntld"), "unknowntld");
+    /// ```
+    pub fn public_suffix<'d>(&self, domain: &'d str) -> &'d str {
+        let domain = domain.strip_suffix('.').unwrap_or(domain);
+        let lowercase = domain.to_ascii_lowercase();
+
+        // walk the suffixes from the longest to the shortest, the first match wins
+        let mut suffixes = std::iter::once(0)
+            .chain(lowercase.match_indices('.').map(|(i, _)| i + 1))
+            .map(|i| (i, &lowercase[i..]));
+
+        let start = suffixes
+            .find_map(|(i, suffix)| {
+                if self.exceptions.contains(suffix) {
+                    return Some(suffix.find('.').map_or(i, |dot| i + dot + 1));
+                }
+
+                let parent = suffix.split_once('.').map(|(_, parent)| parent);
+
+                if self.rules.contains(suffix) || parent.is_some_and(|p| self.wildcards.contains(p))
+                {
+                    return Some(i);
+                }
+
+                None
+            })
+            .unwrap_or_else(|| lowercase.rfind('.').map_or(0, |dot| dot + 1));
+
+        &domain[start..]
+    }
+
+    /// Returns `true` if `domain` is itself a public suffix.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::psl::PublicSuffixList;
+    ///
+    /// let list = PublicSuffixList::bundled();
+    ///
+    /// assert!(list.is_public_suffix("co.uk"));
+    /// assert!(!list.is_public_suffix("example.co.uk"));
+    /// ```
+    pub fn is_public_suffix(&self, domain: &str) -> bool {
+        let domain = domain.strip_prefix('.').unwrap_or(domain);
+
+        !domain.is_empty() && self.public_suffix(domain).len() == domain.trim_end_matches('.').len()
+    }
+}
+
+/// Converts a domain with non-ASCII labels to its ASCII form, returning `None`
+/// if it is ASCII already.
+fn to_ascii(domain: &str) -> Option<String> {
+    if domain.is_ascii() {
+        return None;
+    }
+
+    let labels = domain
+        .split('.')
+        .map(|label| {
+            if label.is_ascii() {
+                Some(label.to_owned())
+            } else {
+                punycode(label).map(|encoded| format!("xn--{encoded}"))
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(labels.join("."))
+}
+
+/// Encodes a label with Punycode, as specified in RFC 3492.
+fn punycode(label: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+
+    fn adapt(delta: u32, points: u32, first: bool) -> u32 {
+        let mut delta = if first { delta / 700 } else { delta / 2 };
+        delta += delta / points;
+
+        let mut k = 0;
+        while delta > (BASE - T_MIN) * T_MAX / 2 {
+            delta /= BASE - T_MIN;
+            k += BASE;
+        }
+
+        k + (BASE - T_MIN + 1) * delta / (delta + 38)
+    }
+
+    fn digit(d: u32) -> char {
+        match d {
+            0..=25 => char::from(b'a' + d as u8),
+            _ => char::from(b'0' + (d - 26) as u8),
+        }
+    }
+
+    let input = label.chars().map(u32::from).collect::<Vec<_>>();
+    let mut output = label.chars().filter(char::is_ascii).collect::<String>();
+
+    let basic = output.len() as u32;
+    let mut handled = basic;
+
+    if basic > 0 {
+        output.push('-');
+    }
+
+    let (mut n, mut delta, mut bias) = (128, 0u32, 72);
+
+    while (handled as usize) < input.len() {
+        let m = input.iter().copied().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for &c in &input {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+
+                loop {
+                    let t = k.saturating_sub(bias).clamp(T_MIN, T_MAX);
+
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+
+                output.push(digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta += 1;
+        n += 1;
+    }
+
+    Some(output)
+}
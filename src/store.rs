@@ -0,0 +1,451 @@
+//! Client-side cookie storage.
+//!
+//! This module provides `CookieStore`, the user agent counterpart of
+//! `CookieJar`. It ingests `Set-Cookie` response headers for a request URL
+//! following the storage model of
+//! [RFC 6265 §5.3](https://datatracker.ietf.org/doc/html/rfc6265#section-5.3)
+//! and builds the `Cookie` request header for later requests.
+//!
+//! # Example
+//! ```
+//! use cookie_rs::CookieStore;
+//!
+//! let mut store = CookieStore::default();
+//! store
+//!     .insert("https://example.com/account/login", "session=abc123; Path=/; Secure")
+//!     .unwrap();
+//! store
+//!     .insert("https://example.com/account/login", "theme=dark")
+//!     .unwrap();
+//!
+//! let header = store.cookie_header("https://example.com/account/settings");
+//! assert_eq!(header.as_deref(), Some("theme=dark; session=abc123"));
+//!
+//! assert_eq!(store.cookie_header("http://example.com/"), None);
+//! ```
+#[cfg(feature = "psl")]
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "psl")]
+use crate::psl::PublicSuffixList;
+use crate::{Cookie, Expiration};
+
+use self::matching::{default_path, domain_match, path_match};
+use self::url::RequestUrl;
+
+pub use self::error::StoreError;
+#[cfg(feature = "reqwest")]
+pub use self::reqwest::SharedCookieStore;
+
+pub mod error;
+mod matching;
+mod netscape;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod url;
+
+/// A client-side store of cookies received in `Set-Cookie` response headers.
+#[derive(Debug, Clone, Default)]
+pub struct CookieStore {
+    entries: Vec<Entry>,
+    next_creation: u64,
+    #[cfg(feature = "psl")]
+    public_suffix_list: Option<Arc<PublicSuffixList>>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    cookie: Cookie<'static>,
+    domain: String,
+    path: String,
+    host_only: bool,
+    expiry: Option<Expiration>,
+    creation: u64,
+}
+
+impl CookieStore {
+    /// Creates an empty `CookieStore`.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let store = CookieStore::new();
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a `Set-Cookie` header value received for `url` and stores the cookie.
+    ///
+    /// Cookies whose `Max-Age` or `Expires` lies in the past remove any stored
+    /// cookie with the same name, domain and path.
+    ///
+    /// # Arguments
+    /// - `url`: The URL of the request the response belongs to.
+    /// - `set_cookie`: The `Set-Cookie` header value.
+    ///
+    /// # Returns
+    /// A `Result` that is an error if the URL or header is malformed or if the
+    /// cookie must be rejected for `url`.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    ///
+    /// assert!(store.insert("https://example.com/", "a=1; Domain=example.com").is_ok());
+    /// assert!(store.insert("https://example.com/", "b=2; Domain=other.com").is_err());
+    /// ```
+    pub fn insert(&mut self, url: &str, set_cookie: &str) -> Result<(), StoreError> {
+        let cookie = Cookie::parse(set_cookie)?.into_owned();
+
+        self.insert_cookie(url, cookie)
+    }
+
+    /// Stores an already parsed cookie received for `url`.
+    ///
+    /// # Arguments
+    /// - `url`: The URL of the request the response belongs to.
+    /// - `cookie`: The cookie to store.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.insert_cookie("https://example.com/", Cookie::new("a", "1")).unwrap();
+    ///
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    pub fn insert_cookie(&mut self, url: &str, cookie: Cookie<'_>) -> Result<(), StoreError> {
+        let url = RequestUrl::parse(url)?;
+        let now = Expiration::now();
+
+        #[cfg(feature = "psl")]
+        let is_public_suffix = |domain: &str| self.public_suffix_list().is_public_suffix(domain);
+        #[cfg(not(feature = "psl"))]
+        let is_public_suffix = |_: &str| false;
+
+        let expiry = match (cookie.max_age(), cookie.expires()) {
+            (Some(max_age), _) => Some(expiration_after(now, max_age)),
+            (None, expires) => expires,
+        };
+
+        let (domain, host_only) = match canonical_domain(&cookie) {
+            Some(domain) => match check_domain(&url.host, &domain, is_public_suffix)? {
+                true => (url.host.clone(), true),
+                false => (domain, false),
+            },
+            None => (url.host.clone(), true),
+        };
+
+        if cookie.secure().is_some_and(|v| v) && !url.secure {
+            return Err(StoreError::NotSecure);
+        }
+
+        let path = match cookie.path() {
+            Some(path) if path.starts_with('/') => path.to_owned(),
+            _ => default_path(url.path).to_owned(),
+        };
+
+        let existing = self
+            .entries
+            .iter()
+            .position(|e| e.cookie.name() == cookie.name() && e.domain == domain && e.path == path);
+
+        let creation = match existing {
+            Some(index) => self.entries.remove(index).creation,
+            None => {
+                self.next_creation += 1;
+                self.next_creation
+            }
+        };
+
+        if expiry.is_some_and(|e| e <= now) {
+            return Ok(());
+        }
+
+        self.entries.push(Entry {
+            cookie: cookie.into_owned(),
+            domain,
+            path,
+            host_only,
+            expiry,
+            creation,
+        });
+
+        Ok(())
+    }
+
+    /// Stores every `Set-Cookie` header value received for `url`.
+    ///
+    /// Malformed or rejected cookies are skipped, as a user agent would.
+    ///
+    /// # Arguments
+    /// - `url`: The URL of the request the response belongs to.
+    /// - `set_cookies`: The `Set-Cookie` header values.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.store_response_cookies("https://example.com/", ["a=1", "=invalid", "b=2"]);
+    ///
+    /// assert_eq!(store.len(), 2);
+    /// ```
+    pub fn store_response_cookies<'h, I>(&mut self, url: &str, set_cookies: I)
+    where
+        I: IntoIterator<Item = &'h str>,
+    {
+        for set_cookie in set_cookies {
+            let _ = self.insert(url, set_cookie);
+        }
+    }
+
+    /// Returns the cookies that should be sent with a request to `url`.
+    ///
+    /// Cookies with longer paths are listed first, then cookies created earlier.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.insert("https://example.com/", "a=1; Path=/docs").unwrap();
+    ///
+    /// assert_eq!(store.matches("https://example.com/docs/intro").len(), 1);
+    /// assert!(store.matches("https://example.com/").is_empty());
+    /// ```
+    pub fn matches(&self, url: &str) -> Vec<&Cookie<'static>> {
+        let Ok(url) = RequestUrl::parse(url) else {
+            return Vec::new();
+        };
+
+        let path = match url.path {
+            "" => "/",
+            path => path,
+        };
+        let now = Expiration::now();
+
+        let mut entries = self
+            .entries
+            .iter()
+            .filter(|e| match e.host_only {
+                true => url.host == e.domain,
+                false => domain_match(&url.host, &e.domain),
+            })
+            .filter(|e| path_match(path, &e.path))
+            .filter(|e| url.secure || !e.cookie.secure().is_some_and(|v| v))
+            .filter(|e| e.expiry.is_none_or(|v| v > now))
+            .collect::<Vec<_>>();
+
+        entries.sort_by(|a, b| {
+            b.path
+                .len()
+                .cmp(&a.path.len())
+                .then(a.creation.cmp(&b.creation))
+        });
+
+        entries.into_iter().map(|e| &e.cookie).collect()
+    }
+
+    /// Builds the `Cookie` request header value for a request to `url`.
+    ///
+    /// Returns `None` if no stored cookie matches.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.insert("https://example.com/", "a=1").unwrap();
+    /// store.insert("https://example.com/", "b=2").unwrap();
+    ///
+    /// assert_eq!(store.cookie_header("https://example.com/").as_deref(), Some("a=1; b=2"));
+    /// ```
+    pub fn cookie_header(&self, url: &str) -> Option<String> {
+        let pairs = self
+            .matches(url)
+            .into_iter()
+            .map(|c| Cookie::new(c.name(), c.value()).to_string())
+            .collect::<Vec<_>>();
+
+        (!pairs.is_empty()).then(|| pairs.join("; "))
+    }
+
+    /// Returns an iterator over all stored cookies, including expired ones not yet purged.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.insert("https://example.com/", "a=1").unwrap();
+    ///
+    /// assert_eq!(store.iter().next().unwrap().name(), "a");
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Cookie<'static>> + '_ {
+        self.entries.iter().map(|e| &e.cookie)
+    }
+
+    /// Removes a cookie by its name, domain and path.
+    ///
+    /// Returns the removed cookie, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.insert("https://example.com/docs/intro", "a=1").unwrap();
+    ///
+    /// assert!(store.remove("a", "example.com", "/docs").is_some());
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn remove(&mut self, name: &str, domain: &str, path: &str) -> Option<Cookie<'static>> {
+        let index = self.entries.iter().position(|e| {
+            e.cookie.name() == name && e.domain.eq_ignore_ascii_case(domain) && e.path == path
+        })?;
+
+        Some(self.entries.remove(index).cookie)
+    }
+
+    /// Removes all cookies whose expiry lies in the past.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.insert("https://example.com/", "a=1").unwrap();
+    /// store.remove_expired();
+    ///
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    pub fn remove_expired(&mut self) {
+        let now = Expiration::now();
+
+        self.entries.retain(|e| e.expiry.is_none_or(|v| v > now));
+    }
+
+    /// Sets the Public Suffix List used to reject `Domain` attributes.
+    ///
+    /// By default the list bundled with the crate is used.
+    ///
+    /// # Arguments
+    /// - `list`: The list to use.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::psl::PublicSuffixList;
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.set_public_suffix_list(PublicSuffixList::parse("example"));
+    ///
+    /// assert!(store.insert("https://a.example/", "a=1; Domain=example").is_err());
+    /// ```
+    #[cfg(feature = "psl")]
+    pub fn set_public_suffix_list(&mut self, list: PublicSuffixList) {
+        self.public_suffix_list = Some(Arc::new(list));
+    }
+
+    #[cfg(feature = "psl")]
+    fn public_suffix_list(&self) -> &PublicSuffixList {
+        match self.public_suffix_list.as_deref() {
+            Some(list) => list,
+            None => PublicSuffixList::bundled(),
+        }
+    }
+
+    /// Removes all cookies from the store.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of stored cookies.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the store contains no cookies.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Cookie<'_> {
+    /// Checks whether a user agent would accept this cookie from `host`.
+    ///
+    /// The `Domain` attribute, if any, must domain-match `host`. With the `psl`
+    /// feature it must also not be a public suffix, unless it equals `host`.
+    ///
+    /// # Arguments
+    /// - `host`: The host of the request the cookie was received for.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::builder("a", "1").domain("example.com").build();
+    ///
+    /// assert!(cookie.validate_for_host("www.example.com").is_ok());
+    /// assert!(cookie.validate_for_host("example.org").is_err());
+    /// ```
+    pub fn validate_for_host(&self, host: &str) -> Result<(), StoreError> {
+        #[cfg(feature = "psl")]
+        let is_public_suffix = crate::psl::is_public_suffix;
+        #[cfg(not(feature = "psl"))]
+        let is_public_suffix = |_: &str| false;
+
+        match canonical_domain(self) {
+            Some(domain) => {
+                check_domain(&host.to_ascii_lowercase(), &domain, is_public_suffix).map(|_| ())
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the lowercase `Domain` attribute without a leading dot, ignoring empty values.
+fn canonical_domain(cookie: &Cookie<'_>) -> Option<String> {
+    cookie
+        .domain()
+        .map(|d| d.strip_prefix('.').unwrap_or(d).to_ascii_lowercase())
+        .filter(|d| !d.is_empty())
+}
+
+/// Checks a canonical `Domain` attribute against the request host.
+///
+/// Returns `true` if the attribute must be ignored and the cookie made host-only,
+/// which is the case for a public suffix equal to the host.
+fn check_domain<F>(host: &str, domain: &str, is_public_suffix: F) -> Result<bool, StoreError>
+where
+    F: Fn(&str) -> bool,
+{
+    if is_public_suffix(domain) {
+        return match host == domain {
+            true => Ok(true),
+            false => Err(StoreError::PublicSuffix(domain.to_owned())),
+        };
+    }
+
+    match domain_match(host, domain) {
+        true => Ok(false),
+        false => Err(StoreError::DomainMismatch(domain.to_owned())),
+    }
+}
+
+fn expiration_after(now: Expiration, max_age: Duration) -> Expiration {
+    let secs = i64::try_from(max_age.as_secs()).unwrap_or(i64::MAX);
+
+    Expiration::from_unix_timestamp(now.unix_timestamp().saturating_add(secs))
+}
@@ -0,0 +1,159 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::cookie::parse::ParseError;
+
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoreError {
+    InvalidUrl(String),
+    ParseError(ParseError),
+    DomainMismatch(String),
+    PublicSuffix(String),
+    NotSecure,
+}
+
+/// An error in a Netscape `cookies.txt` file, with the line it occurred on
+/// when reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetscapeError {
+    line: Option<usize>,
+    kind: NetscapeErrorKind,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetscapeErrorKind {
+    Empty,
+    FieldCount(usize),
+    MissingDomain,
+    EmptyName,
+    InvalidFlag(String),
+    InvalidExpiry(String),
+    InvalidValue(String),
+    InvalidCharacter(char),
+}
+
+/// An error reading a browser cookie database.
+#[cfg(feature = "sqlite")]
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum SqliteError {
+    Sqlite(rusqlite::Error),
+    InvalidSameSite(i64),
+    InvalidPriority(i64),
+    InvalidValue(String),
+}
+
+impl NetscapeError {
+    pub(crate) fn new(line: usize, kind: NetscapeErrorKind) -> Self {
+        Self {
+            line: Some(line),
+            kind,
+        }
+    }
+
+    pub(crate) fn serialize(kind: NetscapeErrorKind) -> Self {
+        Self { line: None, kind }
+    }
+
+    /// Returns the 1-based number of the offending line, or `None` for an
+    /// error while writing.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Returns what is wrong with the line.
+    pub fn kind(&self) -> &NetscapeErrorKind {
+        &self.kind
+    }
+}
+
+impl Error for StoreError {}
+
+impl From<ParseError> for StoreError {
+    fn from(value: ParseError) -> Self {
+        Self::ParseError(value)
+    }
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::InvalidUrl(url) => write!(f, "invalid request url: {url}"),
+            StoreError::ParseError(err) => write!(f, "failed to parse Set-Cookie: {err}"),
+            StoreError::DomainMismatch(domain) => {
+                write!(f, "request host does not match Domain: {domain}")
+            }
+            StoreError::PublicSuffix(domain) => write!(f, "Domain is a public suffix: {domain}"),
+            StoreError::NotSecure => write!(f, "Secure cookie set from an insecure url."),
+        }
+    }
+}
+
+impl Error for NetscapeError {}
+
+impl fmt::Display for NetscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl fmt::Display for NetscapeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetscapeErrorKind::Empty => write!(f, "line is empty or a comment."),
+            NetscapeErrorKind::FieldCount(count) => {
+                write!(f, "expected 7 tab-separated fields, found {count}")
+            }
+            NetscapeErrorKind::MissingDomain => write!(f, "missing domain."),
+            NetscapeErrorKind::EmptyName => write!(f, "cookie name is empty."),
+            NetscapeErrorKind::InvalidFlag(flag) => {
+                write!(f, "expected TRUE or FALSE, found: {flag}")
+            }
+            NetscapeErrorKind::InvalidExpiry(expiry) => write!(f, "invalid expiry: {expiry}"),
+            NetscapeErrorKind::InvalidValue(value) => {
+                write!(f, "value is not valid percent-encoded UTF-8: {value}")
+            }
+            NetscapeErrorKind::InvalidCharacter(c) => {
+                write!(f, "field contains a tab or line break: {c:?}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Error for SqliteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SqliteError::Sqlite(err) => Some(err),
+            SqliteError::InvalidSameSite(_)
+            | SqliteError::InvalidPriority(_)
+            | SqliteError::InvalidValue(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for SqliteError {
+    fn from(value: rusqlite::Error) -> Self {
+        Self::Sqlite(value)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl fmt::Display for SqliteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqliteError::Sqlite(err) => write!(f, "failed to read cookie database: {err}"),
+            SqliteError::InvalidSameSite(value) => write!(f, "invalid samesite value: {value}"),
+            SqliteError::InvalidPriority(value) => write!(f, "invalid priority value: {value}"),
+            SqliteError::InvalidValue(value) => {
+                write!(f, "value is not valid percent-encoded UTF-8: {value}")
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Domain and path matching from RFC 6265 §5.1.3 and §5.1.4.
+
+/// Returns `true` if `host` domain-matches `domain`.
+///
+/// Both arguments are expected to be canonicalized (lowercase).
+pub(crate) fn domain_match(host: &str, domain: &str) -> bool {
+    if host == domain {
+        return true;
+    }
+
+    host.strip_suffix(domain)
+        .is_some_and(|prefix| prefix.ends_with('.') && !is_ip_address(host))
+}
+
+/// Returns `true` if `request_path` path-matches `cookie_path`.
+pub(crate) fn path_match(request_path: &str, cookie_path: &str) -> bool {
+    match request_path.strip_prefix(cookie_path) {
+        Some("") => true,
+        Some(rest) => cookie_path.ends_with('/') || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+/// Computes the default cookie path for a request path.
+pub(crate) fn default_path(request_path: &str) -> &str {
+    if !request_path.starts_with('/') {
+        return "/";
+    }
+
+    match request_path.rfind('/') {
+        Some(0) | None => "/",
+        Some(index) => &request_path[..index],
+    }
+}
+
+pub(crate) fn is_ip_address(host: &str) -> bool {
+    host.starts_with('[') || host.parse::<std::net::Ipv4Addr>().is_ok()
+}
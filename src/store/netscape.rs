@@ -0,0 +1,313 @@
+//! The Netscape `cookies.txt` format, as read and written by curl and wget.
+//!
+//! Each line holds one cookie as seven tab-separated fields: domain, whether
+//! subdomains are included, path, secure flag, expiry as a unix timestamp
+//! (`0` for session cookies), name and value. `HttpOnly` cookies have their
+//! domain prefixed with `#HttpOnly_`, other lines starting with `#` are comments.
+//! Values are written as they appear in a `Cookie` header, so they are
+//! percent-decoded when read and encoded when written.
+
+use std::borrow::Cow;
+
+use super::error::{NetscapeError, NetscapeErrorKind};
+use super::{expiration_after, CookieStore, Entry};
+use crate::cookie::decode_value;
+use crate::{Cookie, Expiration};
+
+const HTTP_ONLY_PREFIX: &str = "#HttpOnly_";
+const HEADER: &str = "# Netscape HTTP Cookie File\n";
+
+/// A single parsed line of a `cookies.txt` file.
+struct Line<'l> {
+    domain: &'l str,
+    include_subdomains: bool,
+    path: &'l str,
+    secure: bool,
+    expiry: Option<Expiration>,
+    name: &'l str,
+    value: Cow<'l, str>,
+    http_only: bool,
+}
+
+impl CookieStore {
+    /// Parses a Netscape `cookies.txt` file into a `CookieStore`.
+    ///
+    /// Comments and blank lines are skipped. Cookies are stored as they are,
+    /// without checking their domain against the Public Suffix List.
+    ///
+    /// # Arguments
+    /// - `content`: The content of the file.
+    ///
+    /// # Returns
+    /// A `Result` that is an error carrying the line number of the first
+    /// malformed line.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let content = "# Netscape HTTP Cookie File\n\
+    ///     .example.com\tTRUE\t/\tTRUE\t0\tsession\tabc123\n\
+    ///     #HttpOnly_example.com\tFALSE\t/docs\tFALSE\t0\ttheme\tdark\n";
+    ///
+    /// let store = CookieStore::from_netscape(content).unwrap();
+    ///
+    /// assert_eq!(store.len(), 2);
+    /// assert_eq!(
+    ///     store.cookie_header("https://www.example.com/").as_deref(),
+    ///     Some("session=abc123")
+    /// );
+    /// ```
+    pub fn from_netscape(content: &str) -> Result<Self, NetscapeError> {
+        let mut store = Self::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let Some(line) =
+                parse_line(line).map_err(|kind| NetscapeError::new(index + 1, kind))?
+            else {
+                continue;
+            };
+
+            let mut cookie = Cookie::new(line.name.to_owned(), line.value.into_owned());
+            cookie.set_path(line.path.to_owned());
+
+            if line.include_subdomains {
+                cookie.set_domain(line.domain.to_owned());
+            }
+
+            if let Some(expiry) = line.expiry {
+                cookie.set_expires(expiry);
+            }
+
+            if line.secure {
+                cookie.set_secure(true);
+            }
+
+            if line.http_only {
+                cookie.set_http_only(true);
+            }
+
+            store.next_creation += 1;
+            store.entries.push(Entry {
+                cookie,
+                domain: line.domain.to_ascii_lowercase(),
+                path: line.path.to_owned(),
+                host_only: !line.include_subdomains,
+                expiry: line.expiry,
+                creation: store.next_creation,
+            });
+        }
+
+        Ok(store)
+    }
+
+    /// Writes the store in the Netscape `cookies.txt` format.
+    ///
+    /// Fails if the domain, path or name of a cookie contains a tab or a line
+    /// break, which cannot be represented in the format. The same applies to
+    /// the value when the `percent-encoding` feature is disabled.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let mut store = CookieStore::new();
+    /// store.insert("https://example.com/", "session=abc123; HttpOnly").unwrap();
+    ///
+    /// assert_eq!(
+    ///     store.to_netscape().unwrap(),
+    ///     "# Netscape HTTP Cookie File\n#HttpOnly_example.com\tFALSE\t/\tFALSE\t0\tsession\tabc123\n"
+    /// );
+    /// ```
+    pub fn to_netscape(&self) -> Result<String, NetscapeError> {
+        let lines = self.entries.iter().map(|e| {
+            let line = format_line(&Line {
+                domain: &e.domain,
+                include_subdomains: !e.host_only,
+                path: &e.path,
+                secure: e.cookie.secure().is_some_and(|v| v),
+                expiry: e.expiry,
+                name: e.cookie.name(),
+                value: e.cookie.encoded_value(),
+                http_only: e.cookie.http_only().is_some_and(|v| v),
+            })?;
+
+            Ok(line + "\n")
+        });
+
+        std::iter::once(Ok(HEADER.to_owned()))
+            .chain(lines)
+            .collect()
+    }
+}
+
+impl Cookie<'static> {
+    /// Parses a single line of a Netscape `cookies.txt` file.
+    ///
+    /// The domain is always carried in the `Domain` attribute, so a cookie
+    /// restricted to its host cannot be told apart from one that includes
+    /// subdomains. Use [`CookieStore::from_netscape`] to keep the distinction.
+    ///
+    /// # Arguments
+    /// - `line`: The line to parse.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::from_netscape(".example.com\tTRUE\t/\tTRUE\t1761031680\tid\t1").unwrap();
+    ///
+    /// assert_eq!(cookie.domain(), Some("example.com"));
+    /// assert_eq!(cookie.secure(), Some(true));
+    /// assert_eq!(cookie.expires().unwrap().unix_timestamp(), 1761031680);
+    /// ```
+    pub fn from_netscape(line: &str) -> Result<Self, NetscapeError> {
+        let line = parse_line(line)
+            .and_then(|line| line.ok_or(NetscapeErrorKind::Empty))
+            .map_err(|kind| NetscapeError::new(1, kind))?;
+
+        let mut cookie = Cookie::new(line.name.to_owned(), line.value.into_owned());
+        cookie.set_domain(line.domain.to_owned());
+        cookie.set_path(line.path.to_owned());
+
+        if let Some(expiry) = line.expiry {
+            cookie.set_expires(expiry);
+        }
+
+        if line.secure {
+            cookie.set_secure(true);
+        }
+
+        if line.http_only {
+            cookie.set_http_only(true);
+        }
+
+        Ok(cookie)
+    }
+}
+
+impl Cookie<'_> {
+    /// Formats the cookie as a line of a Netscape `cookies.txt` file.
+    ///
+    /// The `Domain` attribute is required and always includes subdomains. The
+    /// path defaults to `/`, and a `Max-Age` is turned into an expiry relative
+    /// to now. Fails if the domain, path or name contains a tab or a line
+    /// break, or the value does when the `percent-encoding` feature is disabled.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::prelude::*;
+    ///
+    /// let cookie = Cookie::builder("id", "1").domain("example.com").secure(true).build();
+    ///
+    /// assert_eq!(cookie.to_netscape().unwrap(), ".example.com\tTRUE\t/\tTRUE\t0\tid\t1");
+    /// assert!(Cookie::new("id", "1").to_netscape().is_err());
+    /// ```
+    pub fn to_netscape(&self) -> Result<String, NetscapeError> {
+        let domain = self
+            .domain()
+            .map(|d| d.strip_prefix('.').unwrap_or(d))
+            .filter(|d| !d.is_empty())
+            .ok_or(NetscapeError::serialize(NetscapeErrorKind::MissingDomain))?;
+
+        let expiry = match (self.max_age(), self.expires()) {
+            (Some(max_age), _) => Some(expiration_after(Expiration::now(), max_age)),
+            (None, expires) => expires,
+        };
+
+        format_line(&Line {
+            domain,
+            include_subdomains: true,
+            path: self.path().unwrap_or("/"),
+            secure: self.secure().is_some_and(|v| v),
+            expiry,
+            name: self.name(),
+            value: self.encoded_value(),
+            http_only: self.http_only().is_some_and(|v| v),
+        })
+    }
+}
+
+/// Parses a line, returning `None` for comments and blank lines.
+fn parse_line(line: &str) -> Result<Option<Line<'_>>, NetscapeErrorKind> {
+    let line = line.trim_end_matches(['\r', '\n']);
+
+    let (line, http_only) = match line.strip_prefix(HTTP_ONLY_PREFIX) {
+        Some(line) => (line, true),
+        None if line.starts_with('#') || line.trim().is_empty() => return Ok(None),
+        None => (line, false),
+    };
+
+    let fields = line.split('\t').collect::<Vec<_>>();
+
+    let [domain, include_subdomains, path, secure, expiry, name, value] = fields[..] else {
+        return Err(NetscapeErrorKind::FieldCount(fields.len()));
+    };
+
+    let domain = domain.strip_prefix('.').unwrap_or(domain);
+
+    if domain.is_empty() {
+        return Err(NetscapeErrorKind::MissingDomain);
+    }
+
+    if name.is_empty() {
+        return Err(NetscapeErrorKind::EmptyName);
+    }
+
+    let expiry = match expiry.parse::<i64>() {
+        Ok(0) => None,
+        Ok(secs) => Some(Expiration::from_unix_timestamp(secs)),
+        Err(_) => return Err(NetscapeErrorKind::InvalidExpiry(expiry.to_owned())),
+    };
+
+    let value =
+        decode_value(value).ok_or_else(|| NetscapeErrorKind::InvalidValue(value.to_owned()))?;
+
+    Ok(Some(Line {
+        domain,
+        include_subdomains: parse_flag(include_subdomains)?,
+        path,
+        secure: parse_flag(secure)?,
+        expiry,
+        name,
+        value,
+        http_only,
+    }))
+}
+
+fn parse_flag(flag: &str) -> Result<bool, NetscapeErrorKind> {
+    if flag.eq_ignore_ascii_case("TRUE") {
+        Ok(true)
+    } else if flag.eq_ignore_ascii_case("FALSE") {
+        Ok(false)
+    } else {
+        Err(NetscapeErrorKind::InvalidFlag(flag.to_owned()))
+    }
+}
+
+fn format_line(line: &Line<'_>) -> Result<String, NetscapeError> {
+    let flag = |v: bool| if v { "TRUE" } else { "FALSE" };
+
+    // a tab or line break would shift the fields of the line or split it
+    if let Some(c) = [line.domain, line.path, line.name, &line.value]
+        .iter()
+        .find_map(|field| field.chars().find(|c| matches!(c, '\t' | '\n' | '\r')))
+    {
+        return Err(NetscapeError::serialize(
+            NetscapeErrorKind::InvalidCharacter(c),
+        ));
+    }
+
+    Ok(format!(
+        "{}{}{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        if line.http_only { HTTP_ONLY_PREFIX } else { "" },
+        if line.include_subdomains { "." } else { "" },
+        line.domain,
+        flag(line.include_subdomains),
+        line.path,
+        flag(line.secure),
+        line.expiry.map_or(0, |e| e.unix_timestamp()),
+        line.name,
+        line.value,
+    ))
+}
@@ -0,0 +1,87 @@
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use reqwest::cookie;
+use reqwest::header::HeaderValue;
+use reqwest::Url;
+
+use super::CookieStore;
+
+/// A [`CookieStore`] that can be shared between threads and `reqwest` clients.
+///
+/// Implements `reqwest::cookie::CookieStore`, so it can be passed to
+/// `ClientBuilder::cookie_provider` behind an `Arc`. Several clients given
+/// the same `Arc` share their cookies.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+///
+/// use cookie_rs::store::SharedCookieStore;
+///
+/// let store = Arc::new(SharedCookieStore::default());
+///
+/// let client = reqwest::Client::builder()
+///     .cookie_provider(Arc::clone(&store))
+///     .build()
+///     .unwrap();
+///
+/// assert!(store.read().is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedCookieStore(RwLock<CookieStore>);
+
+impl SharedCookieStore {
+    /// Creates a new `SharedCookieStore` wrapping `store`.
+    ///
+    /// # Arguments
+    /// - `store`: The store to share.
+    pub fn new(store: CookieStore) -> Self {
+        Self(RwLock::new(store))
+    }
+
+    /// Locks the store for reading.
+    pub fn read(&self) -> RwLockReadGuard<'_, CookieStore> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the store for modification.
+    ///
+    /// # Example
+    /// ```
+    /// use cookie_rs::store::SharedCookieStore;
+    ///
+    /// let store = SharedCookieStore::default();
+    /// store.write().insert("https://example.com/", "a=1").unwrap();
+    ///
+    /// assert_eq!(store.read().len(), 1);
+    /// ```
+    pub fn write(&self) -> RwLockWriteGuard<'_, CookieStore> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consumes the `SharedCookieStore`, returning the wrapped store.
+    pub fn into_inner(self) -> CookieStore {
+        self.0.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<CookieStore> for SharedCookieStore {
+    fn from(value: CookieStore) -> Self {
+        Self::new(value)
+    }
+}
+
+impl cookie::CookieStore for SharedCookieStore {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let set_cookies = cookie_headers.filter_map(|v| std::str::from_utf8(v.as_bytes()).ok());
+
+        self.write()
+            .store_response_cookies(url.as_str(), set_cookies);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let header = self.read().cookie_header(url.as_str())?;
+
+        HeaderValue::try_from(header).ok()
+    }
+}
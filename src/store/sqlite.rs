@@ -0,0 +1,209 @@
+//! Import of the cookie databases of Firefox and Chromium-based browsers.
+//!
+//! Firefox keeps its cookies in the `moz_cookies` table of `cookies.sqlite`,
+//! Chromium in the `cookies` table of the `Cookies` file of a profile. Both
+//! store a host starting with a dot for cookies that include subdomains.
+
+use std::path::Path;
+
+use rusqlite::{Connection, OpenFlags};
+
+use super::error::SqliteError;
+use super::{CookieStore, Entry};
+use crate::cookie::{decode_value, Priority, SameSite};
+use crate::{Cookie, Expiration};
+
+/// Seconds between the Windows epoch (1601-01-01) used by Chromium and the Unix epoch.
+const WINDOWS_EPOCH_OFFSET: i64 = 11_644_473_600;
+
+const FIREFOX_QUERY: &str =
+    "SELECT host, name, value, path, expiry, isSecure, isHttpOnly, sameSite \
+     FROM moz_cookies ORDER BY creationTime";
+const CHROMIUM_QUERY: &str = "SELECT host_key, name, value, length(encrypted_value), path, \
+     expires_utc, is_secure, is_httponly, samesite, priority FROM cookies ORDER BY creation_utc";
+
+/// A single row of a browser cookie table.
+struct Row {
+    host: String,
+    name: String,
+    value: String,
+    path: String,
+    expiry: Option<Expiration>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+    priority: Option<Priority>,
+}
+
+impl CookieStore {
+    /// Reads the `moz_cookies` table of a Firefox `cookies.sqlite` database.
+    ///
+    /// The database is opened read-only. Cookies are stored as they are, in
+    /// order of creation, without checking their domain against the Public
+    /// Suffix List.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the `cookies.sqlite` file.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let store = CookieStore::from_firefox("profile/cookies.sqlite").unwrap();
+    /// let header = store.cookie_header("https://example.com/");
+    /// ```
+    pub fn from_firefox<P: AsRef<Path>>(path: P) -> Result<Self, SqliteError> {
+        let conn = open(path.as_ref())?;
+        let mut stmt = conn.prepare(FIREFOX_QUERY)?;
+        let mut rows = stmt.query([])?;
+        let mut store = Self::new();
+
+        while let Some(row) = rows.next()? {
+            let expiry: i64 = row.get(4)?;
+            let same_site = match row.get(7)? {
+                0 => Some(SameSite::None),
+                1 => Some(SameSite::Lax),
+                2 => Some(SameSite::Strict),
+                other => return Err(SqliteError::InvalidSameSite(other)),
+            };
+
+            store.push_row(Row {
+                host: row.get(0)?,
+                name: row.get(1)?,
+                value: row.get(2)?,
+                path: row.get(3)?,
+                expiry: (expiry != 0).then(|| Expiration::from_unix_timestamp(expiry)),
+                secure: row.get(5)?,
+                http_only: row.get(6)?,
+                same_site,
+                priority: None,
+            })?;
+        }
+
+        Ok(store)
+    }
+
+    /// Reads the `cookies` table of a Chromium `Cookies` database.
+    ///
+    /// The database is opened read-only. Only the plain `value` column is
+    /// read; cookies whose value is only available in `encrypted_value` are
+    /// skipped. Expiry is converted from microseconds since 1601, with `0`
+    /// meaning a session cookie, a `samesite` of `-1` leaves the attribute
+    /// unset, and `priority` is mapped to the `Priority` attribute.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the `Cookies` file.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use cookie_rs::CookieStore;
+    ///
+    /// let store = CookieStore::from_chromium("profile/Default/Cookies").unwrap();
+    /// let header = store.cookie_header("https://example.com/");
+    /// ```
+    pub fn from_chromium<P: AsRef<Path>>(path: P) -> Result<Self, SqliteError> {
+        let conn = open(path.as_ref())?;
+        let mut stmt = conn.prepare(CHROMIUM_QUERY)?;
+        let mut rows = stmt.query([])?;
+        let mut store = Self::new();
+
+        while let Some(row) = rows.next()? {
+            let value: String = row.get(2)?;
+            let encrypted: Option<i64> = row.get(3)?;
+
+            if value.is_empty() && encrypted.is_some_and(|len| len > 0) {
+                continue;
+            }
+
+            let expires_utc: i64 = row.get(5)?;
+            let same_site = match row.get(8)? {
+                -1 => None,
+                0 => Some(SameSite::None),
+                1 => Some(SameSite::Lax),
+                2 => Some(SameSite::Strict),
+                other => return Err(SqliteError::InvalidSameSite(other)),
+            };
+            let priority = match row.get(9)? {
+                0 => Priority::Low,
+                1 => Priority::Medium,
+                2 => Priority::High,
+                other => return Err(SqliteError::InvalidPriority(other)),
+            };
+
+            store.push_row(Row {
+                host: row.get(0)?,
+                name: row.get(1)?,
+                value,
+                path: row.get(4)?,
+                expiry: (expires_utc != 0).then(|| {
+                    Expiration::from_unix_timestamp(
+                        expires_utc.div_euclid(1_000_000) - WINDOWS_EPOCH_OFFSET,
+                    )
+                }),
+                secure: row.get(6)?,
+                http_only: row.get(7)?,
+                same_site,
+                priority: Some(priority),
+            })?;
+        }
+
+        Ok(store)
+    }
+
+    fn push_row(&mut self, row: Row) -> Result<(), SqliteError> {
+        let (domain, host_only) = match row.host.strip_prefix('.') {
+            Some(domain) => (domain.to_ascii_lowercase(), false),
+            None => (row.host.to_ascii_lowercase(), true),
+        };
+
+        // browsers store the value as it appeared in the Set-Cookie header
+        let Some(value) = decode_value(&row.value).map(|v| v.into_owned()) else {
+            return Err(SqliteError::InvalidValue(row.value));
+        };
+
+        let mut cookie = Cookie::new(row.name, value);
+        cookie.set_path(row.path.clone());
+
+        if !host_only {
+            cookie.set_domain(domain.clone());
+        }
+
+        if let Some(expiry) = row.expiry {
+            cookie.set_expires(expiry);
+        }
+
+        if row.secure {
+            cookie.set_secure(true);
+        }
+
+        if row.http_only {
+            cookie.set_http_only(true);
+        }
+
+        if let Some(same_site) = row.same_site {
+            cookie.set_same_site(same_site);
+        }
+
+        if let Some(priority) = row.priority {
+            cookie.set_priority(priority);
+        }
+
+        self.next_creation += 1;
+        self.entries.push(Entry {
+            cookie,
+            domain,
+            path: row.path,
+            host_only,
+            expiry: row.expiry,
+            creation: self.next_creation,
+        });
+
+        Ok(())
+    }
+}
+
+fn open(path: &Path) -> Result<Connection, SqliteError> {
+    let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+
+    Ok(Connection::open_with_flags(path, flags)?)
+}
@@ -0,0 +1,47 @@
+use super::StoreError;
+
+/// The parts of a request URL relevant to cookie storage and retrieval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RequestUrl<'u> {
+    pub secure: bool,
+    pub host: String,
+    pub path: &'u str,
+}
+
+impl<'u> RequestUrl<'u> {
+    /// Parses an absolute `scheme://host[:port][/path][?query][#fragment]` URL.
+    pub fn parse(url: &'u str) -> Result<Self, StoreError> {
+        let invalid = || StoreError::InvalidUrl(url.to_owned());
+
+        let (scheme, rest) = url.split_once("://").ok_or_else(invalid)?;
+
+        if scheme.is_empty()
+            || !scheme
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(&b))
+        {
+            return Err(invalid());
+        }
+
+        let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let (authority, rest) = rest.split_at(authority_end);
+
+        let host_port = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+        let host = match host_port.strip_prefix('[') {
+            Some(ipv6) => &host_port[..ipv6.find(']').ok_or_else(invalid)? + 2],
+            None => host_port.split(':').next().unwrap_or_default(),
+        };
+
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        let path_end = rest.find(['?', '#']).unwrap_or(rest.len());
+
+        Ok(Self {
+            secure: scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("wss"),
+            host: host.to_ascii_lowercase(),
+            path: &rest[..path_end],
+        })
+    }
+}
@@ -0,0 +1,246 @@
+#![cfg(feature = "actix")]
+
+use std::time::Duration;
+
+use actix_web::cookie as actix;
+use actix_web::http::header::{COOKIE, SET_COOKIE};
+use actix_web::test as actix_test;
+use actix_web::{web, App, HttpResponse};
+use cookie_rs::error::{ActixCookieError, ValidationError};
+use cookie_rs::prelude::*;
+
+async fn whoami(jar: CookieJar<'static>) -> String {
+    jar.get("user")
+        .map_or("anonymous", |c| c.value())
+        .to_owned()
+}
+
+async fn login(mut jar: CookieJar<'static>) -> Result<HttpResponse, ValidationError> {
+    jar.add(Cookie::builder("session", "abc123").path("/").build());
+    jar.remove("user");
+
+    let mut response = HttpResponse::Ok();
+    jar.write_to_response(&mut response)?;
+
+    Ok(response.finish())
+}
+
+async fn invalid(mut jar: CookieJar<'static>) -> Result<HttpResponse, ValidationError> {
+    jar.add(Cookie::new("theme", "dark"));
+    jar.add(Cookie::new("__Host-session", "abc123"));
+
+    let mut response = HttpResponse::Ok();
+    jar.write_to_response(&mut response)?;
+
+    Ok(response.finish())
+}
+
+#[actix_web::test]
+async fn extract_jar() {
+    let app = actix_test::init_service(App::new().route("/", web::get().to(whoami))).await;
+
+    let request = actix_test::TestRequest::get()
+        .uri("/")
+        .append_header((COOKIE, "session=1"))
+        .append_header((COOKIE, "user=bob"))
+        .to_request();
+    let body = actix_test::call_and_read_body(&app, request).await;
+
+    assert_eq!(body, "bob");
+}
+
+#[actix_web::test]
+async fn extract_skips_malformed_pairs() {
+    let app = actix_test::init_service(App::new().route("/", web::get().to(whoami))).await;
+
+    let request = actix_test::TestRequest::get()
+        .uri("/")
+        .append_header((COOKIE, "novalue; user=bob; bad=%FF"))
+        .to_request();
+    let response = actix_test::call_service(&app, request).await;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(actix_test::read_body(response).await, "bob");
+}
+
+#[test]
+fn try_from_request_rejects_malformed_pair() {
+    let request = actix_test::TestRequest::default()
+        .append_header((COOKIE, "a=1; novalue"))
+        .to_http_request();
+
+    assert!(CookieJar::try_from_request(&request).is_err());
+
+    let request = actix_test::TestRequest::default()
+        .append_header((COOKIE, "a=1"))
+        .append_header((COOKIE, "b=2"))
+        .to_http_request();
+
+    assert_eq!(CookieJar::try_from_request(&request).unwrap().len(), 2);
+}
+
+#[actix_web::test]
+async fn write_changes() {
+    let app = actix_test::init_service(App::new().route("/", web::get().to(login))).await;
+
+    let request = actix_test::TestRequest::get()
+        .uri("/")
+        .append_header((COOKIE, "user=bob"))
+        .to_request();
+    let response = actix_test::call_service(&app, request).await;
+
+    let headers: Vec<_> = response
+        .headers()
+        .get_all(SET_COOKIE)
+        .map(|v| v.to_str().unwrap().to_owned())
+        .collect();
+
+    assert_eq!(headers.len(), 2);
+    assert!(headers.contains(&"session=abc123; Path=/".to_owned()));
+    assert!(headers.iter().any(|h| h.starts_with("user=;")));
+}
+
+#[actix_web::test]
+async fn write_rejects_invalid_change() {
+    let app = actix_test::init_service(App::new().route("/", web::get().to(invalid))).await;
+
+    let request = actix_test::TestRequest::get().uri("/").to_request();
+    let response = actix_test::call_service(&app, request).await;
+
+    assert_eq!(response.status(), 500);
+    assert!(response.headers().get(SET_COOKIE).is_none());
+}
+
+#[test]
+fn into_actix_cookie() {
+    let cookie = Cookie::builder("session", "abc123")
+        .domain("example.com")
+        .path("/app")
+        .expires(Expiration::from_unix_timestamp(1761031680))
+        .max_age(Duration::from_secs(60))
+        .http_only(true)
+        .secure(true)
+        .same_site(SameSite::None)
+        .build();
+
+    let actix = actix::Cookie::try_from(&cookie).unwrap();
+
+    assert_eq!(actix.name(), "session");
+    assert_eq!(actix.value(), "abc123");
+    assert_eq!(actix.domain(), Some("example.com"));
+    assert_eq!(actix.path(), Some("/app"));
+    assert_eq!(
+        actix.expires_datetime().unwrap().unix_timestamp(),
+        1761031680
+    );
+    assert_eq!(actix.max_age(), Some(actix::time::Duration::seconds(60)));
+    assert_eq!(actix.http_only(), Some(true));
+    assert_eq!(actix.secure(), Some(true));
+    assert_eq!(actix.same_site(), Some(actix::SameSite::None));
+}
+
+#[test]
+fn roundtrip_is_lossless() {
+    let cookie = Cookie::builder("session", "abc123")
+        .domain("example.com")
+        .path("/")
+        .expires(Expiration::from_unix_timestamp(0))
+        .max_age(Duration::from_secs(3600))
+        .http_only(false)
+        .secure(true)
+        .same_site(SameSite::Strict)
+        .build();
+
+    let actix = actix::Cookie::try_from(&cookie).unwrap();
+
+    assert_eq!(Cookie::from(&actix), cookie);
+}
+
+#[test]
+fn from_actix_cookie() {
+    let actix = actix::Cookie::parse("id=1; Max-Age=-5; SameSite=Lax; HttpOnly").unwrap();
+    let cookie = Cookie::from(&actix);
+
+    assert_eq!(cookie.max_age(), Some(Duration::ZERO));
+    assert_eq!(cookie.same_site(), Some(SameSite::Lax));
+    assert_eq!(cookie.http_only(), Some(true));
+    assert_eq!(cookie.secure(), None);
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn encoded_value() {
+    let cookie = Cookie::new("c", "a b;");
+    let actix = actix::Cookie::try_from(&cookie).unwrap();
+
+    assert_eq!(actix.value(), "a%20b%3B");
+    assert_eq!(actix.to_string(), cookie.to_string());
+    assert_eq!(Cookie::from(&actix), cookie);
+
+    let actix = actix::Cookie::new("c", "a%3Db");
+
+    assert_eq!(Cookie::from(&actix).value(), "a=b");
+    assert_eq!(Cookie::from(&actix::Cookie::new("c", "%FF")).value(), "%FF");
+}
+
+#[test]
+fn partitioned_is_not_dropped() {
+    let cookie = Cookie::builder("id", "1").partitioned(true).build();
+
+    assert_eq!(
+        actix::Cookie::try_from(&cookie),
+        Err(ActixCookieError::Partitioned)
+    );
+}
+
+#[test]
+fn priority_and_extensions_are_not_dropped() {
+    let cookie = Cookie::builder("id", "1").priority(Priority::High).build();
+
+    assert_eq!(
+        actix::Cookie::try_from(&cookie),
+        Err(ActixCookieError::Priority)
+    );
+
+    let cookie = Cookie::builder("id", "1")
+        .extension_flag("SameParty")
+        .build();
+
+    assert_eq!(
+        actix::Cookie::try_from(&cookie),
+        Err(ActixCookieError::Extension("SameParty".to_owned()))
+    );
+}
+
+#[test]
+fn huge_max_age_is_capped() {
+    let cookie = Cookie::builder("id", "1")
+        .max_age(Duration::from_secs(u64::MAX))
+        .build();
+
+    let actix = actix::Cookie::try_from(&cookie).unwrap();
+
+    assert_eq!(
+        actix.max_age(),
+        Some(actix::time::Duration::seconds(i64::MAX))
+    );
+}
+
+#[test]
+fn write_partitioned_to_response() {
+    let mut jar = CookieJar::default();
+    jar.add(
+        Cookie::builder("id", "1")
+            .partitioned(true)
+            .secure(true)
+            .build(),
+    );
+
+    let mut response = HttpResponse::Ok();
+    jar.write_to_response(&mut response).unwrap();
+
+    assert_eq!(
+        response.finish().headers().get(SET_COOKIE).unwrap(),
+        "id=1; Partitioned; Secure"
+    );
+}
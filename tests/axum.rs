@@ -0,0 +1,142 @@
+#![cfg(feature = "axum")]
+
+use axum::body::Body;
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::{Request, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use cookie_rs::prelude::*;
+use tower::ServiceExt;
+
+async fn whoami(jar: CookieJar<'static>) -> String {
+    jar.get("user")
+        .map_or("anonymous", |c| c.value())
+        .to_owned()
+}
+
+async fn login(mut jar: CookieJar<'static>) -> (CookieJar<'static>, &'static str) {
+    jar.add(Cookie::builder("session", "abc123").path("/").build());
+    jar.remove("user");
+
+    (jar, "ok")
+}
+
+async fn invalid(mut jar: CookieJar<'static>) -> (CookieJar<'static>, &'static str) {
+    jar.add(Cookie::new("__Host-session", "abc123"));
+
+    (jar, "ok")
+}
+
+async fn control_path(mut jar: CookieJar<'static>) -> (CookieJar<'static>, &'static str) {
+    jar.add(Cookie::new("theme", "dark"));
+    jar.add(Cookie::builder("session", "abc123").path("/a\x01").build());
+
+    (jar, "ok")
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/whoami", get(whoami))
+        .route("/login", get(login))
+        .route("/invalid", get(invalid))
+        .route("/control", get(control_path))
+}
+
+async fn body(response: axum::response::Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn extract_jar() {
+    let request = Request::get("/whoami")
+        .header(COOKIE, "session=1")
+        .header(COOKIE, "user=bob")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response).await, "bob");
+}
+
+#[tokio::test]
+async fn extract_without_cookies() {
+    let request = Request::get("/whoami").body(Body::empty()).unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    assert_eq!(body(response).await, "anonymous");
+}
+
+#[tokio::test]
+async fn extract_skips_malformed_pairs() {
+    let request = Request::get("/whoami")
+        .header(COOKIE, "novalue; user=bob; bad=%FF")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body(response).await, "bob");
+}
+
+#[tokio::test]
+async fn response_emits_changes() {
+    let request = Request::get("/login")
+        .header(COOKIE, "user=bob")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    let headers: Vec<_> = response
+        .headers()
+        .get_all(SET_COOKIE)
+        .iter()
+        .map(|v| v.to_str().unwrap().to_owned())
+        .collect();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(headers.len(), 2);
+    assert!(headers.contains(&"session=abc123; Path=/".to_owned()));
+    assert!(headers.iter().any(|h| h.starts_with("user=;")));
+}
+
+#[tokio::test]
+async fn response_without_changes() {
+    let request = Request::get("/whoami")
+        .header(COOKIE, "user=bob")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    assert!(response.headers().get(SET_COOKIE).is_none());
+}
+
+#[tokio::test]
+async fn response_rejects_invalid_cookie() {
+    let request = Request::get("/invalid").body(Body::empty()).unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response.headers().get(SET_COOKIE).is_none());
+}
+
+#[tokio::test]
+async fn response_rejects_control_character() {
+    let request = Request::get("/control").body(Body::empty()).unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response.headers().get(SET_COOKIE).is_none());
+    assert_eq!(body(response).await, "invalid character in Path: '\\u{1}'");
+}
@@ -0,0 +1,142 @@
+use cookie_rs::cookie::date;
+use cookie_rs::error::*;
+use cookie_rs::prelude::*;
+
+const SUN_06_NOV_1994: Expiration = Expiration::from_unix_timestamp(784111777);
+
+#[test]
+fn date_imf_fixdate() {
+    assert_eq!(
+        date::parse("Sun, 06 Nov 1994 08:49:37 GMT"),
+        Ok(SUN_06_NOV_1994)
+    );
+}
+
+#[test]
+fn date_rfc850() {
+    assert_eq!(
+        date::parse("Sunday, 06-Nov-94 08:49:37 GMT"),
+        Ok(SUN_06_NOV_1994)
+    );
+}
+
+#[test]
+fn date_asctime() {
+    assert_eq!(date::parse("Sun Nov  6 08:49:37 1994"), Ok(SUN_06_NOV_1994));
+}
+
+#[test]
+fn date_dashed_four_digit_year() {
+    assert_eq!(
+        date::parse("Sun, 06-Nov-1994 08:49:37 GMT"),
+        Ok(SUN_06_NOV_1994)
+    );
+}
+
+#[test]
+fn date_odd_delimiters() {
+    assert_eq!(date::parse("06/nov/1994;08:49:37"), Ok(SUN_06_NOV_1994));
+}
+
+#[test]
+fn date_full_month_name() {
+    assert_eq!(date::parse("6 November 1994 08:49:37"), Ok(SUN_06_NOV_1994));
+}
+
+#[test]
+fn date_single_digit_time_fields() {
+    let expected = Expiration::from_unix_timestamp(784080000 + 8 * 3600 + 9 * 60 + 7);
+
+    assert_eq!(date::parse("Sun, 06 Nov 1994 8:9:7 GMT"), Ok(expected));
+}
+
+#[test]
+fn date_time_with_trailing_octets() {
+    assert_eq!(date::parse("06 Nov 1994 08:49:37GMT"), Ok(SUN_06_NOV_1994));
+}
+
+#[test]
+fn date_two_digit_year_before_70() {
+    let expected = Expiration::from_unix_timestamp(1262304000);
+
+    assert_eq!(date::parse("Fri, 01-Jan-10 00:00:00 GMT"), Ok(expected));
+}
+
+#[test]
+fn date_two_digit_year_from_70() {
+    assert_eq!(
+        date::parse("Thu, 01-Jan-70 00:00:00 GMT"),
+        Ok(Expiration::UNIX_EPOCH)
+    );
+}
+
+#[test]
+fn date_ignores_weekday_mismatch() {
+    assert_eq!(
+        date::parse("Mon, 06 Nov 1994 08:49:37 GMT"),
+        Ok(SUN_06_NOV_1994)
+    );
+}
+
+#[test]
+fn date_missing_time() {
+    assert_eq!(
+        date::parse("Sun, 06 Nov 1994"),
+        Err(ParseExpiresError::MissingTime)
+    );
+}
+
+#[test]
+fn date_missing_month() {
+    assert_eq!(
+        date::parse("Sun, 06 1994 08:49:37 GMT"),
+        Err(ParseExpiresError::MissingMonth)
+    );
+}
+
+#[test]
+fn date_missing_year() {
+    assert_eq!(
+        date::parse("Sun, 06 Nov 08:49:37 GMT"),
+        Err(ParseExpiresError::MissingYear)
+    );
+}
+
+#[test]
+fn date_invalid_day_of_month() {
+    assert_eq!(
+        date::parse("Sun, 00 Nov 1994 08:49:37 GMT"),
+        Err(ParseExpiresError::InvalidDayOfMonth(0))
+    );
+}
+
+#[test]
+fn date_invalid_year() {
+    assert_eq!(
+        date::parse("Sun, 06 Nov 1600 08:49:37 GMT"),
+        Err(ParseExpiresError::InvalidYear(1600))
+    );
+}
+
+#[test]
+fn date_invalid_time() {
+    assert_eq!(
+        date::parse("Sun, 06 Nov 1994 08:60:37 GMT"),
+        Err(ParseExpiresError::InvalidTime(8, 60, 37))
+    );
+}
+
+#[test]
+fn date_nonexistent_date() {
+    assert_eq!(
+        date::parse("Sat, 29 Feb 2025 00:00:00 GMT"),
+        Err(ParseExpiresError::InvalidDate(2025, 2, 29))
+    );
+}
+
+#[test]
+fn date_leap_day() {
+    let expected = Expiration::from_unix_timestamp(951782400);
+
+    assert_eq!(date::parse("Tue, 29 Feb 2000 00:00:00 GMT"), Ok(expected));
+}
@@ -0,0 +1,165 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use cookie_rs::error::*;
+use cookie_rs::prelude::*;
+
+#[test]
+fn expiration_display_epoch() {
+    assert_eq!(
+        Expiration::UNIX_EPOCH.to_string(),
+        "Thu, 01 Jan 1970 00:00:00 GMT"
+    );
+}
+
+#[test]
+fn expiration_display_leap_day() {
+    let expires = Expiration::from_unix_timestamp(951_782_400);
+
+    assert_eq!(expires.to_string(), "Tue, 29 Feb 2000 00:00:00 GMT");
+}
+
+#[test]
+fn expiration_display_before_epoch() {
+    let expires = Expiration::from_unix_timestamp(-1);
+
+    assert_eq!(expires.to_string(), "Wed, 31 Dec 1969 23:59:59 GMT");
+}
+
+#[test]
+fn expiration_display_clamps_to_four_digit_years() {
+    assert_eq!(
+        Expiration::from_unix_timestamp(i64::MAX).to_string(),
+        "Fri, 31 Dec 9999 23:59:59 GMT"
+    );
+    assert_eq!(
+        Expiration::from_unix_timestamp(i64::MIN).to_string(),
+        "Mon, 01 Jan 0001 00:00:00 GMT"
+    );
+    assert_eq!(
+        Expiration::from_unix_timestamp(i64::MAX).to_iso8601(),
+        "9999-12-31T23:59:59.000Z"
+    );
+    assert_eq!(
+        Expiration::from_unix_timestamp(i64::MIN).to_iso8601(),
+        "0001-01-01T00:00:00.000Z"
+    );
+
+    let expires = "Fri, 31 Dec 9999 23:59:59 GMT";
+    assert_eq!(expires.parse::<Expiration>().unwrap().to_string(), expires);
+}
+
+#[test]
+fn expiration_parse_imf_fixdate() {
+    let expected = Expiration::from_unix_timestamp(784_111_777);
+
+    assert_eq!("Sun, 06 Nov 1994 08:49:37 GMT".parse(), Ok(expected));
+}
+
+#[test]
+fn expiration_roundtrip() {
+    for secs in [0, 784_111_777, 951_782_400, 1_761_031_680, 253_402_300_799] {
+        let expires = Expiration::from_unix_timestamp(secs);
+
+        assert_eq!(expires.to_string().parse(), Ok(expires));
+    }
+}
+
+#[test]
+fn expiration_parse_rejects_invalid_time() {
+    let input = "Sun, 06 Nov 1994 24:00:00 GMT";
+    let expected = Err(ParseExpiresError::InvalidTime(24, 0, 0));
+
+    assert_eq!(input.parse::<Expiration>(), expected);
+}
+
+#[test]
+fn expiration_system_time_conversion() {
+    let time = UNIX_EPOCH + Duration::from_secs(1_761_031_680);
+    let expires = Expiration::from(time);
+
+    assert_eq!(expires.unix_timestamp(), 1_761_031_680);
+    assert_eq!(SystemTime::from(expires), time);
+}
+
+#[test]
+fn expiration_system_time_saturates() {
+    let min = SystemTime::from(Expiration::from_unix_timestamp(i64::MIN));
+    let max = SystemTime::from(Expiration::from_unix_timestamp(i64::MAX));
+
+    assert!(min < UNIX_EPOCH);
+    assert!(max > UNIX_EPOCH + Duration::from_secs(1_761_031_680));
+    assert!(min.checked_sub(Duration::from_secs(1)).is_none());
+    assert!(max.checked_add(Duration::from_secs(1)).is_none());
+}
+
+#[test]
+fn expiration_compared_to_now() {
+    let past = Expiration::from(SystemTime::now() - Duration::from_secs(60));
+    let future = Expiration::from(SystemTime::now() + Duration::from_secs(60));
+
+    assert!(past.is_expired());
+    assert!(!future.is_expired());
+    assert!(past < Expiration::now() && Expiration::now() < future);
+}
+
+#[test]
+fn expiration_iso8601_formatting() {
+    assert_eq!(
+        Expiration::UNIX_EPOCH.to_iso8601(),
+        "1970-01-01T00:00:00.000Z"
+    );
+    assert_eq!(
+        Expiration::from_unix_timestamp(951_782_400).to_iso8601(),
+        "2000-02-29T00:00:00.000Z"
+    );
+}
+
+#[test]
+fn expiration_iso8601_parsing() {
+    let expected = Some(Expiration::from_unix_timestamp(1_761_031_680));
+
+    assert_eq!(Expiration::parse_iso8601("2025-10-21T07:28:00Z"), expected);
+    assert_eq!(
+        Expiration::parse_iso8601("2025-10-21T07:28:00.999Z"),
+        expected
+    );
+    assert_eq!(Expiration::parse_iso8601("2025-10-21T07:28:00"), expected);
+    assert_eq!(
+        Expiration::parse_iso8601("2025-10-21T04:58:00-02:30"),
+        expected
+    );
+    assert_eq!(
+        Expiration::parse_iso8601("2025-10-21T09:28:00+0200"),
+        expected
+    );
+}
+
+#[test]
+fn expiration_iso8601_roundtrip() {
+    for secs in [0, 784_111_777, 1_761_031_680, 4_102_444_799] {
+        let expires = Expiration::from_unix_timestamp(secs);
+
+        assert_eq!(
+            Expiration::parse_iso8601(&expires.to_iso8601()),
+            Some(expires)
+        );
+    }
+}
+
+#[test]
+fn expiration_iso8601_rejects_invalid() {
+    for input in [
+        "",
+        "2025-10-21",
+        "2025-13-01T00:00:00Z",
+        "2025-02-29T00:00:00Z",
+        "2025-10-21T24:00:00Z",
+        "2025-10-21T07:28Z",
+        "25-10-21T07:28:00Z",
+        "2025-10-21T07:28:00+2",
+        "2025-10-21T07:28:00UTC",
+        "Tue, 21 Oct 2025 07:28:00 GMT",
+    ] {
+        assert_eq!(Expiration::parse_iso8601(input), None, "{input}");
+    }
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "har")]
+
+use cookie_rs::error::*;
+use cookie_rs::har::{self, HarCookie};
+use cookie_rs::prelude::*;
+
+const LOG: &str = r#"{
+  "log": {
+    "version": "1.2",
+    "creator": {"name": "test", "version": "1"},
+    "entries": [
+      {
+        "startedDateTime": "2025-10-21T07:28:00.000Z",
+        "request": {
+          "method": "GET",
+          "url": "https://example.com/login",
+          "cookies": [
+            {"name": "session", "value": "old"},
+            {"name": "theme", "value": "dark"}
+          ]
+        },
+        "response": {
+          "status": 200,
+          "cookies": [
+            {
+              "name": "session",
+              "value": "abc123",
+              "path": "/",
+              "domain": "example.com",
+              "expires": "2025-10-21T07:28:00.000Z",
+              "httpOnly": true,
+              "secure": true,
+              "sameSite": "Lax"
+            }
+          ]
+        }
+      },
+      {
+        "request": {"url": "https://example.com/", "cookies": []},
+        "response": {"cookies": []}
+      }
+    ]
+  }
+}"#;
+
+#[test]
+fn cookie_jars_per_entry() {
+    let entries = har::cookie_jars(LOG).unwrap();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].url, "https://example.com/login");
+    assert_eq!(entries[0].request.len(), 2);
+    assert_eq!(entries[0].request.get("session").unwrap().value(), "old");
+    assert!(entries[1].request.is_empty());
+    assert!(entries[1].response.changes().is_empty());
+}
+
+#[test]
+fn response_cookies_as_changes() {
+    let entries = har::cookie_jars(LOG).unwrap();
+
+    assert_eq!(
+        entries[0].response.as_header_values(),
+        ["session=abc123; Domain=example.com; Expires=Tue, 21 Oct 2025 07:28:00 GMT; HttpOnly; Path=/; SameSite=Lax; Secure"]
+    );
+}
+
+#[test]
+fn cookie_to_har() {
+    let cookie = Cookie::builder("session", "abc123")
+        .path("/")
+        .domain("example.com")
+        .expires(Expiration::from_unix_timestamp(1761031680))
+        .http_only(true)
+        .secure(true)
+        .same_site(SameSite::Strict)
+        .build();
+
+    let json = serde_json::to_string(&HarCookie::from(&cookie)).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"name":"session","value":"abc123","path":"/","domain":"example.com","expires":"2025-10-21T07:28:00.000Z","httpOnly":true,"secure":true,"sameSite":"Strict"}"#
+    );
+}
+
+#[test]
+fn har_roundtrip() {
+    let cookie = Cookie::builder("id", "1")
+        .path("/app")
+        .expires(Expiration::from_unix_timestamp(0))
+        .secure(false)
+        .same_site(SameSite::None)
+        .build();
+
+    assert_eq!(Cookie::try_from(HarCookie::from(&cookie)), Ok(cookie));
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn encoded_value() {
+    let har = HarCookie {
+        name: "c".into(),
+        value: "a%3Db%20c".into(),
+        ..Default::default()
+    };
+    let cookie = Cookie::try_from(har).unwrap();
+
+    assert_eq!(cookie.value(), "a=b c");
+    assert_eq!(cookie.to_string(), "c=a=b%20c");
+    assert_eq!(HarCookie::from(&Cookie::new("c", "a b%")).value, "a%20b%25");
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn invalid_value() {
+    let har = HarCookie {
+        name: "a".into(),
+        value: "%FF".into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        Cookie::try_from(har),
+        Err(HarError::InvalidValue("%FF".into()))
+    );
+}
+
+#[test]
+fn minimal_har_cookie() {
+    let har: HarCookie =
+        serde_json::from_str(r#"{"name":"a","value":"1","expires":null}"#).unwrap();
+
+    assert_eq!(Cookie::try_from(har), Ok(Cookie::new("a", "1")));
+}
+
+#[test]
+fn invalid_expires() {
+    let har = HarCookie {
+        name: "a".into(),
+        expires: Some("tomorrow".into()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        Cookie::try_from(har),
+        Err(HarError::InvalidExpires("tomorrow".into()))
+    );
+}
+
+#[test]
+fn invalid_same_site() {
+    let har = HarCookie {
+        name: "a".into(),
+        same_site: Some("Sometimes".into()),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        Cookie::try_from(har),
+        Err(HarError::ParseSameSiteError(_))
+    ));
+}
+
+#[test]
+fn invalid_json() {
+    assert!(matches!(
+        har::cookie_jars("{\"log\": 1}"),
+        Err(HarError::InvalidJson(_))
+    ));
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "http")]
+
+use cookie_rs::error::{SetCookieError, ValidationError};
+use cookie_rs::prelude::*;
+use http::header::{COOKIE, SET_COOKIE};
+use http::{HeaderMap, HeaderValue};
+
+#[test]
+fn from_headers_single() {
+    let mut headers = HeaderMap::new();
+    headers.insert(COOKIE, HeaderValue::from_static("a=1; b=2"));
+
+    let jar = CookieJar::from_headers(&headers);
+
+    assert_eq!(jar.get("a").unwrap().value(), "1");
+    assert_eq!(jar.get("b").unwrap().value(), "2");
+}
+
+#[test]
+fn from_headers_merges_lines() {
+    let mut headers = HeaderMap::new();
+    headers.append(COOKIE, HeaderValue::from_static("a=1"));
+    headers.append(COOKIE, HeaderValue::from_static("b=2;"));
+    headers.append(COOKIE, HeaderValue::from_static("c=3"));
+
+    let jar = CookieJar::from_headers(&headers);
+
+    assert_eq!(jar.len(), 3);
+    assert_eq!(jar.get("c").unwrap().value(), "3");
+}
+
+#[test]
+fn from_headers_empty() {
+    let jar = CookieJar::from_headers(&HeaderMap::new());
+
+    assert!(jar.is_empty());
+}
+
+#[test]
+fn from_headers_skips_invalid_utf8() {
+    let mut headers = HeaderMap::new();
+    headers.append(COOKIE, HeaderValue::from_bytes(b"bad=\xff").unwrap());
+    headers.append(COOKIE, HeaderValue::from_static("a=1"));
+
+    let jar = CookieJar::from_headers(&headers);
+
+    assert_eq!(jar.len(), 1);
+}
+
+#[test]
+fn from_headers_skips_malformed_pairs() {
+    let mut headers = HeaderMap::new();
+    headers.append(COOKIE, HeaderValue::from_static("a=1; junk; b=2"));
+    headers.append(COOKIE, HeaderValue::from_static("c=%FF; d=4"));
+
+    let jar = CookieJar::from_headers(&headers);
+
+    #[cfg(feature = "percent-encoding")]
+    assert_eq!(jar.len(), 3);
+    assert_eq!(jar.get("b").unwrap().value(), "2");
+    assert_eq!(jar.get("d").unwrap().value(), "4");
+}
+
+#[test]
+fn try_from_headers_rejects_malformed_pair() {
+    let mut headers = HeaderMap::new();
+    headers.append(COOKIE, HeaderValue::from_static("a=1; junk; b=2"));
+
+    assert!(CookieJar::try_from_headers(&headers).is_err());
+
+    headers.insert(COOKIE, HeaderValue::from_static("a=1; b=2"));
+
+    assert_eq!(CookieJar::try_from_headers(&headers).unwrap().len(), 2);
+}
+
+#[test]
+fn from_headers_ignores_other_headers() {
+    let mut headers = HeaderMap::new();
+    headers.append(SET_COOKIE, HeaderValue::from_static("a=1"));
+
+    assert!(CookieJar::from_headers(&headers).is_empty());
+}
+
+#[test]
+fn write_to_appends_each_change() {
+    let mut jar = CookieJar::default();
+    jar.add(Cookie::builder("a", "1").path("/").build());
+    jar.add(Cookie::new("b", "2"));
+    jar.remove("c");
+
+    let mut headers = HeaderMap::new();
+    headers.append(SET_COOKIE, HeaderValue::from_static("existing=1"));
+    jar.write_to(&mut headers).unwrap();
+
+    let values: Vec<_> = headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .map(|v| v.to_str().unwrap())
+        .collect();
+
+    assert_eq!(values.len(), 4);
+    assert_eq!(values[0], "existing=1");
+    assert!(values.contains(&"a=1; Path=/"));
+    assert!(values.contains(&"b=2"));
+}
+
+#[test]
+fn write_to_rejects_invalid_header() {
+    let mut jar = CookieJar::default();
+    jar.add(Cookie::new("a", "1"));
+    jar.add(Cookie::builder("b", "2").path("/\n").build());
+
+    let mut headers = HeaderMap::new();
+
+    assert_eq!(
+        jar.write_to(&mut headers),
+        Err(SetCookieError::ValidationError(
+            ValidationError::InvalidPath('\n')
+        ))
+    );
+    assert!(headers.is_empty());
+}
+
+#[test]
+fn write_to_validates_changes() {
+    let mut jar = CookieJar::default();
+    jar.add(Cookie::new("a", "1"));
+    jar.add(Cookie::new("__Host-b", "2"));
+
+    let mut headers = HeaderMap::new();
+
+    assert!(matches!(
+        jar.write_to(&mut headers),
+        Err(SetCookieError::ValidationError(
+            ValidationError::PrefixError(_)
+        ))
+    ));
+    assert!(headers.is_empty());
+}
+
+#[test]
+fn header_value_from_cookie() {
+    let cookie = Cookie::builder("session", "abc123")
+        .http_only(true)
+        .secure(true)
+        .build();
+
+    let value = HeaderValue::try_from(&cookie).unwrap();
+
+    assert_eq!(value, "session=abc123; HttpOnly; Secure");
+}
@@ -1,3 +1,4 @@
+use cookie_rs::jar::split_cookie_header;
 use cookie_rs::prelude::*;
 
 #[test]
@@ -87,6 +88,21 @@ fn cookie_jar_parse_cookie_header() {
     assert_eq!(cookie2.value(), "value2");
 }
 
+#[test]
+fn cookie_jar_parse_lossy_skips_malformed_pairs() {
+    let input = "a=1; junk; =2; b=2";
+
+    assert!(CookieJar::parse(input).is_err());
+
+    let jar = CookieJar::parse_lossy(input);
+    assert_eq!(jar.len(), 2);
+    assert_eq!(jar.get("a").unwrap().value(), "1");
+    assert_eq!(jar.get("b").unwrap().value(), "2");
+
+    let jar = CookieJar::parse_lossy(input.to_owned());
+    assert_eq!(jar.len(), 2);
+}
+
 #[test]
 fn cookie_jar_empty() {
     let jar = CookieJar::default();
@@ -217,3 +233,235 @@ fn cookie_jar_len_with_parsed() {
 
     assert_eq!(jar.len(), 2);
 }
+
+#[test]
+fn cookie_jar_same_name_different_path() {
+    let mut jar = CookieJar::default();
+
+    jar.add(Cookie::builder("session", "root").path("/").build());
+    jar.add(Cookie::builder("session", "admin").path("/admin").build());
+
+    let headers = jar.as_header_values();
+
+    assert_eq!(headers.len(), 2);
+    assert!(headers.contains(&"session=root; Path=/".to_string()));
+    assert!(headers.contains(&"session=admin; Path=/admin".to_string()));
+}
+
+#[test]
+fn cookie_jar_same_name_different_domain() {
+    let mut jar = CookieJar::default();
+
+    jar.add(Cookie::builder("id", "1").domain("a.example.com").build());
+    jar.add(Cookie::builder("id", "2").domain("b.example.com").build());
+    jar.add(Cookie::new("id", "3"));
+
+    assert_eq!(jar.changes().len(), 3);
+    assert_eq!(jar.get_all("id").count(), 3);
+}
+
+#[test]
+fn cookie_jar_replace_same_identity() {
+    let mut jar = CookieJar::default();
+
+    jar.add(
+        Cookie::builder("id", "1")
+            .domain("Example.com")
+            .path("/")
+            .build(),
+    );
+    jar.add(
+        Cookie::builder("id", "2")
+            .domain(".example.com")
+            .path("/")
+            .build(),
+    );
+
+    assert_eq!(jar.changes().len(), 1);
+    assert_eq!(jar.get("id").unwrap().value(), "2");
+}
+
+#[test]
+fn cookie_jar_path_case_sensitive() {
+    let mut jar = CookieJar::default();
+
+    jar.add(Cookie::builder("id", "1").path("/Admin").build());
+    jar.add(Cookie::builder("id", "2").path("/admin").build());
+
+    assert_eq!(jar.changes().len(), 2);
+}
+
+#[test]
+fn cookie_jar_parse_keeps_duplicates() {
+    let jar = CookieJar::parse("session=a; user=bob; session=b").unwrap();
+
+    let values: Vec<_> = jar.get_all("session").map(|c| c.value()).collect();
+
+    assert_eq!(values, ["a", "b"]);
+    assert_eq!(jar.get("session").unwrap().value(), "a");
+    assert_eq!(jar.len(), 3);
+}
+
+#[test]
+fn cookie_jar_get_skips_removal_of_other_scope() {
+    let mut jar = CookieJar::default();
+
+    jar.add(Cookie::builder("session", "admin").path("/admin").build());
+    jar.remove("session");
+
+    assert_eq!(jar.get("session").unwrap().value(), "admin");
+    assert_eq!(jar.get_all("session").count(), 1);
+}
+
+#[test]
+fn cookie_jar_scoped_add_keeps_parsed() {
+    let mut jar = CookieJar::parse("session=a; session=b").unwrap();
+
+    jar.add(Cookie::builder("session", "admin").path("/admin").build());
+
+    let values: Vec<_> = jar.get_all("session").map(|c| c.value()).collect();
+
+    assert_eq!(values, ["admin", "a", "b"]);
+    assert_eq!(jar.len(), 3);
+
+    jar.remove("session");
+
+    assert_eq!(jar.get("session").unwrap().value(), "admin");
+    assert_eq!(jar.len(), 1);
+}
+
+#[test]
+fn cookie_ord_matches_eq() {
+    let root = Cookie::builder("session", "1").path("/").build();
+    let admin = Cookie::builder("session", "1").path("/admin").build();
+    let dotted = Cookie::builder("session", "1")
+        .domain(".Example.com")
+        .path("/")
+        .build();
+    let plain = Cookie::builder("session", "1")
+        .domain("example.com")
+        .path("/")
+        .build();
+
+    assert_ne!(root, admin);
+    assert!(root < admin);
+    assert_eq!(dotted, plain);
+    assert_eq!(dotted.cmp(&plain), std::cmp::Ordering::Equal);
+    assert_ne!(root, Cookie::builder("session", "2").path("/").build());
+
+    let set = [root.clone(), admin, root]
+        .into_iter()
+        .collect::<std::collections::BTreeSet<_>>();
+
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn cookie_jar_get_all_unknown() {
+    let jar = CookieJar::parse("a=1").unwrap();
+
+    assert_eq!(jar.get_all("b").count(), 0);
+}
+
+#[test]
+fn cookie_jar_removal_header() {
+    let mut jar = CookieJar::default();
+
+    jar.remove("session");
+
+    assert_eq!(
+        jar.as_header_values(),
+        ["session=; Expires=Thu, 01 Jan 1970 00:00:00 GMT; Max-Age=0"]
+    );
+}
+
+#[test]
+fn cookie_jar_removal_mirrors_attributes() {
+    let mut jar = CookieJar::default();
+    let template = Cookie::builder("session", "abc123")
+        .domain("example.com")
+        .path("/app")
+        .secure(true)
+        .http_only(true)
+        .build();
+
+    jar.remove(template);
+
+    assert_eq!(
+        jar.as_header_values(),
+        ["session=; Domain=example.com; Expires=Thu, 01 Jan 1970 00:00:00 GMT; HttpOnly; Max-Age=0; Path=/app; Secure"]
+    );
+}
+
+#[test]
+fn cookie_jar_remove_replaces_matching_add() {
+    let mut jar = CookieJar::default();
+
+    jar.add(Cookie::builder("session", "root").path("/").build());
+    jar.add(Cookie::builder("session", "app").path("/app").build());
+    jar.remove(Cookie::builder("session", "").path("/app").build());
+
+    let headers = jar.as_header_values();
+
+    assert_eq!(headers.len(), 2);
+    assert!(headers.contains(&"session=root; Path=/".to_string()));
+    assert!(headers
+        .iter()
+        .any(|h| h.starts_with("session=;") && h.ends_with("Path=/app")));
+}
+
+#[test]
+fn cookie_jar_remove_by_owned_name() {
+    let mut jar = CookieJar::parse("a=1; b=2; c=3").unwrap();
+    let name = String::from("b");
+
+    jar.remove(String::from("a"));
+    jar.remove(&name);
+    jar.remove(std::borrow::Cow::Borrowed("c"));
+
+    assert!(jar.is_empty());
+    assert_eq!(jar.changes().len(), 3);
+}
+
+#[test]
+fn cookie_jar_remove_parsed_cookie() {
+    let mut jar = CookieJar::parse("session=abc123").unwrap();
+    let cookie = jar.get("session").unwrap().clone();
+
+    jar.remove(cookie);
+
+    assert!(jar.get("session").is_none());
+    assert_eq!(jar.changes().len(), 1);
+    assert!(jar.changes().iter().all(|c| c.is_remove()));
+}
+
+#[test]
+fn split_cookie_header_pairs() {
+    let pairs = split_cookie_header(" a=1 ;b = 2;; c ; =3; d=x=y; e=").collect::<Vec<_>>();
+
+    assert_eq!(pairs, [("a", "1"), ("b", "2"), ("d", "x=y"), ("e", "")]);
+    assert_eq!(split_cookie_header("").next(), None);
+}
+
+#[test]
+fn split_cookie_header_matches_parse() {
+    let header = "session=abc123; theme=dark; session=other";
+    let jar = CookieJar::parse(header).unwrap();
+
+    let parsed = jar
+        .cookie()
+        .map(|c| (c.name(), c.value()))
+        .collect::<Vec<_>>();
+    let split = split_cookie_header(header).collect::<Vec<_>>();
+
+    assert_eq!(parsed, split);
+}
+
+#[test]
+fn cookie_jar_find_in_header() {
+    let header = "theme=dark; session=abc123; session=other; name=a%20b";
+
+    assert_eq!(CookieJar::find_in_header(header, "session"), Some("abc123"));
+    assert_eq!(CookieJar::find_in_header(header, "name"), Some("a%20b"));
+    assert_eq!(CookieJar::find_in_header(header, "Session"), None);
+}
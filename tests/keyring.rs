@@ -0,0 +1,128 @@
+#![cfg(any(feature = "signed", feature = "private"))]
+
+use cookie_rs::prelude::*;
+use cookie_rs::{Key, KeyRing};
+
+fn key(byte: u8) -> Key {
+    Key::try_from(&[byte; 64][..]).unwrap()
+}
+
+#[test]
+fn keyring_active_and_retired() {
+    let ring = KeyRing::new(key(2))
+        .with_retired(key(1))
+        .with_retired(key(0));
+
+    assert_eq!(ring.retired().len(), 2);
+}
+
+#[cfg(feature = "signed")]
+#[test]
+fn keyring_signed_writes_with_active_key() {
+    let ring = KeyRing::new(key(2)).with_retired(key(1));
+    let mut jar = CookieJar::default();
+
+    jar.signed_mut(&ring).add(Cookie::new("session", "abc123"));
+
+    assert!(jar.signed(&key(2)).get("session").is_some());
+    assert!(jar.signed(&key(1)).get("session").is_none());
+}
+
+#[cfg(feature = "signed")]
+#[test]
+fn keyring_signed_reads_retired_key() {
+    let ring = KeyRing::new(key(2)).with_retired(key(1));
+    let mut jar = CookieJar::default();
+
+    jar.signed_mut(&key(1))
+        .add(Cookie::new("session", "abc123"));
+
+    assert_eq!(jar.signed(&ring).get("session").unwrap().value(), "abc123");
+}
+
+#[cfg(feature = "signed")]
+#[test]
+fn keyring_signed_rejects_unknown_key() {
+    let ring = KeyRing::new(key(2)).with_retired(key(1));
+    let mut jar = CookieJar::default();
+
+    jar.signed_mut(&key(3))
+        .add(Cookie::new("session", "abc123"));
+
+    assert!(jar.signed(&ring).get("session").is_none());
+}
+
+#[cfg(feature = "signed")]
+#[test]
+fn keyring_signed_reissues_retired_cookie() {
+    let ring = KeyRing::new(key(2)).with_retired(key(1)).with_reissue(true);
+    let header = {
+        let mut jar = CookieJar::default();
+        jar.signed_mut(&key(1))
+            .add(Cookie::new("session", "abc123"));
+        jar.as_header_values().remove(0)
+    };
+    let mut jar = CookieJar::parse(header).unwrap();
+
+    assert!(jar.changes().is_empty());
+    assert_eq!(
+        jar.signed_mut(&ring).get("session").unwrap().value(),
+        "abc123"
+    );
+    assert_eq!(jar.changes().len(), 1);
+    assert!(jar.signed(&key(2)).get("session").is_some());
+}
+
+#[cfg(feature = "signed")]
+#[test]
+fn keyring_signed_no_reissue_by_default() {
+    let ring = KeyRing::new(key(2)).with_retired(key(1));
+    let mut jar = CookieJar::new([Cookie::new("session", "")]);
+    jar.signed_mut(&key(1))
+        .add(Cookie::new("session", "abc123"));
+    let before = jar.as_header_values();
+
+    assert!(jar.signed_mut(&ring).get("session").is_some());
+    assert_eq!(jar.as_header_values(), before);
+}
+
+#[cfg(feature = "signed")]
+#[test]
+fn keyring_signed_active_key_not_reissued() {
+    let ring = KeyRing::new(key(2)).with_reissue(true);
+    let mut jar = CookieJar::default();
+    jar.signed_mut(&key(2))
+        .add(Cookie::new("session", "abc123"));
+    let before = jar.as_header_values();
+
+    assert!(jar.signed_mut(&ring).get("session").is_some());
+    assert_eq!(jar.as_header_values(), before);
+}
+
+#[cfg(feature = "private")]
+#[test]
+fn keyring_private_reads_retired_key() {
+    let ring = KeyRing::new(key(2)).with_retired(key(1));
+    let mut jar = CookieJar::default();
+
+    jar.private_mut(&key(1)).add(Cookie::new("user", "42"));
+
+    assert_eq!(jar.private(&ring).get("user").unwrap().value(), "42");
+    assert!(jar.private(&key(2)).get("user").is_none());
+}
+
+#[cfg(feature = "private")]
+#[test]
+fn keyring_private_reissues_retired_cookie() {
+    let ring = KeyRing::new(key(2)).with_retired(key(1)).with_reissue(true);
+    let header = {
+        let mut jar = CookieJar::default();
+        jar.private_mut(&key(1)).add(Cookie::new("user", "42"));
+        jar.as_header_values().remove(0)
+    };
+    let mut jar = CookieJar::parse(header).unwrap();
+
+    assert_eq!(jar.private_mut(&ring).get("user").unwrap().value(), "42");
+    assert_eq!(jar.changes().len(), 1);
+    assert_eq!(jar.private(&key(2)).get("user").unwrap().value(), "42");
+}
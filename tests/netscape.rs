@@ -0,0 +1,237 @@
+use cookie_rs::prelude::*;
+use cookie_rs::store::error::NetscapeErrorKind;
+
+const FILE: &str = "# Netscape HTTP Cookie File
+# https://curl.se/docs/http-cookies.html
+
+.example.com\tTRUE\t/\tTRUE\t4102444800\tsession\tabc123
+example.com\tFALSE\t/docs\tFALSE\t0\ttheme\tdark
+#HttpOnly_.api.example.com\tTRUE\t/\tFALSE\t0\ttoken\tt0k3n
+";
+
+#[test]
+fn parse_file() {
+    let store = CookieStore::from_netscape(FILE).unwrap();
+
+    assert_eq!(store.len(), 3);
+    assert_eq!(
+        store
+            .cookie_header("https://example.com/docs/intro")
+            .as_deref(),
+        Some("theme=dark; session=abc123")
+    );
+    assert_eq!(
+        store
+            .cookie_header("https://www.example.com/docs")
+            .as_deref(),
+        Some("session=abc123")
+    );
+    assert_eq!(
+        store.cookie_header("http://v1.api.example.com/").as_deref(),
+        Some("token=t0k3n")
+    );
+}
+
+#[test]
+fn parse_attributes() {
+    let store = CookieStore::from_netscape(FILE).unwrap();
+    let cookies: Vec<_> = store.iter().collect();
+
+    assert_eq!(cookies[0].secure(), Some(true));
+    assert_eq!(cookies[0].expires().unwrap().unix_timestamp(), 4102444800);
+    assert_eq!(cookies[1].expires(), None);
+    assert_eq!(cookies[1].domain(), None);
+    assert_eq!(cookies[2].http_only(), Some(true));
+}
+
+#[test]
+fn roundtrip() {
+    let store = CookieStore::from_netscape(FILE).unwrap();
+    let written = store.to_netscape().unwrap();
+
+    assert_eq!(
+        written,
+        "# Netscape HTTP Cookie File
+.example.com\tTRUE\t/\tTRUE\t4102444800\tsession\tabc123
+example.com\tFALSE\t/docs\tFALSE\t0\ttheme\tdark
+#HttpOnly_.api.example.com\tTRUE\t/\tFALSE\t0\ttoken\tt0k3n
+"
+    );
+    assert_eq!(
+        CookieStore::from_netscape(&written)
+            .unwrap()
+            .to_netscape()
+            .unwrap(),
+        written
+    );
+}
+
+#[test]
+fn crlf_line_endings() {
+    let store = CookieStore::from_netscape("# header\r\nexample.com\tFALSE\t/\tFALSE\t0\ta\t1\r\n")
+        .unwrap();
+
+    assert_eq!(store.iter().next().unwrap().value(), "1");
+}
+
+#[test]
+fn empty_value() {
+    let store = CookieStore::from_netscape("example.com\tFALSE\t/\tFALSE\t0\ta\t").unwrap();
+
+    assert_eq!(store.iter().next().unwrap().value(), "");
+}
+
+#[test]
+fn error_line_numbers() {
+    let content = "# header\n\nexample.com\tFALSE\t/\tFALSE\t0\ta\t1\nexample.com\tMAYBE\t/\tFALSE\t0\tb\t2\n";
+    let err = CookieStore::from_netscape(content).unwrap_err();
+
+    assert_eq!(err.line(), Some(4));
+    assert_eq!(err.kind(), &NetscapeErrorKind::InvalidFlag("MAYBE".into()));
+    assert_eq!(
+        err.to_string(),
+        "line 4: expected TRUE or FALSE, found: MAYBE"
+    );
+}
+
+#[test]
+fn errors() {
+    let kind = |content: &str| {
+        CookieStore::from_netscape(content)
+            .unwrap_err()
+            .kind()
+            .clone()
+    };
+
+    assert_eq!(
+        kind("example.com\tFALSE\t/"),
+        NetscapeErrorKind::FieldCount(3)
+    );
+    assert_eq!(
+        kind("example.com\tFALSE\t/\tFALSE\tsoon\ta\t1"),
+        NetscapeErrorKind::InvalidExpiry("soon".into())
+    );
+    assert_eq!(
+        kind("\tFALSE\t/\tFALSE\t0\ta\t1"),
+        NetscapeErrorKind::MissingDomain
+    );
+    assert_eq!(
+        kind("example.com\tFALSE\t/\tFALSE\t0\t\t1"),
+        NetscapeErrorKind::EmptyName
+    );
+}
+
+#[test]
+fn cookie_from_line() {
+    let cookie =
+        Cookie::from_netscape("#HttpOnly_example.com\tFALSE\t/app\tTRUE\t0\tid\t1").unwrap();
+
+    assert_eq!(cookie.name(), "id");
+    assert_eq!(cookie.domain(), Some("example.com"));
+    assert_eq!(cookie.path(), Some("/app"));
+    assert_eq!(cookie.http_only(), Some(true));
+    assert_eq!(cookie.secure(), Some(true));
+    assert_eq!(cookie.expires(), None);
+}
+
+#[test]
+fn cookie_from_comment() {
+    let err = Cookie::from_netscape("# comment").unwrap_err();
+
+    assert_eq!(err.kind(), &NetscapeErrorKind::Empty);
+}
+
+#[test]
+fn cookie_to_line() {
+    let cookie = Cookie::builder("id", "1")
+        .domain(".example.com")
+        .path("/app")
+        .http_only(true)
+        .expires(Expiration::from_unix_timestamp(1761031680))
+        .build();
+
+    assert_eq!(
+        cookie.to_netscape().unwrap(),
+        "#HttpOnly_.example.com\tTRUE\t/app\tFALSE\t1761031680\tid\t1"
+    );
+}
+
+#[test]
+fn cookie_to_line_without_domain() {
+    let err = Cookie::new("id", "1").to_netscape().unwrap_err();
+
+    assert_eq!(err.kind(), &NetscapeErrorKind::MissingDomain);
+    assert_eq!(err.line(), None);
+    assert_eq!(err.to_string(), "missing domain.");
+}
+
+#[test]
+fn cookie_to_line_rejects_separators() {
+    for cookie in [
+        Cookie::builder("id", "1")
+            .domain("example.com")
+            .path("/a\nb")
+            .build(),
+        Cookie::builder("id\r", "1").domain("example.com").build(),
+    ] {
+        let err = cookie.to_netscape().unwrap_err();
+
+        assert!(matches!(err.kind(), NetscapeErrorKind::InvalidCharacter(_)));
+        assert_eq!(err.line(), None);
+    }
+}
+
+#[cfg(not(feature = "percent-encoding"))]
+#[test]
+fn cookie_to_line_rejects_separator_in_value() {
+    let cookie = Cookie::builder("id", "a\tb").domain("example.com").build();
+
+    assert_eq!(
+        cookie.to_netscape().unwrap_err().kind(),
+        &NetscapeErrorKind::InvalidCharacter('\t')
+    );
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn store_roundtrip_encoded_value() {
+    let mut store = CookieStore::new();
+    store.insert("https://example.com/", "c=a%20b").unwrap();
+    store.insert("https://example.com/", "d=x%09y").unwrap();
+
+    let content = store.to_netscape().unwrap();
+
+    assert!(content.contains("\tc\ta%20b\n"));
+    assert!(content.contains("\td\tx%09y\n"));
+
+    let store = CookieStore::from_netscape(&content).unwrap();
+
+    assert_eq!(store.iter().next().unwrap().value(), "a b");
+    assert_eq!(store.iter().nth(1).unwrap().value(), "x\ty");
+    assert_eq!(
+        store.cookie_header("https://example.com/").as_deref(),
+        Some("c=a%20b; d=x%09y")
+    );
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn invalid_encoding() {
+    let err = CookieStore::from_netscape("example.com\tFALSE\t/\tFALSE\t0\tc\t%FF").unwrap_err();
+
+    assert_eq!(err.line(), Some(1));
+    assert_eq!(
+        err.kind(),
+        &NetscapeErrorKind::InvalidValue("%FF".to_owned())
+    );
+}
+
+#[test]
+fn cookie_roundtrip() {
+    let line = ".example.com\tTRUE\t/\tTRUE\t1761031680\tid\t1";
+
+    assert_eq!(
+        Cookie::from_netscape(line).unwrap().to_netscape().unwrap(),
+        line
+    );
+}
@@ -0,0 +1,107 @@
+//! Cookies parsed from owned strings must stay valid on their own.
+//!
+//! These tests avoid I/O and FFI so they can run under Miri:
+//! `cargo +nightly miri test --test ownership`.
+
+use cookie_rs::prelude::*;
+
+const SET_COOKIE: &str =
+    "session=abc123; Domain=example.com; Path=/app; Expires=Tue, 21 Oct 2025 07:28:00 GMT";
+
+fn parse_owned() -> Cookie<'static> {
+    Cookie::parse(SET_COOKIE.to_owned()).unwrap()
+}
+
+fn assert_session(cookie: &Cookie<'_>) {
+    assert_eq!(cookie.name(), "session");
+    assert_eq!(cookie.value(), "abc123");
+    assert_eq!(cookie.domain(), Some("example.com"));
+    assert_eq!(cookie.path(), Some("/app"));
+    assert_eq!(cookie.expires_raw(), Some("Tue, 21 Oct 2025 07:28:00 GMT"));
+}
+
+#[test]
+fn clone_outlives_original() {
+    let cookie = parse_owned();
+    let clone = cookie.clone();
+
+    drop(cookie);
+
+    assert_session(&clone);
+}
+
+#[test]
+fn move_keeps_attributes() {
+    let cookie = parse_owned();
+    let boxed = Box::new(cookie);
+    let mut cookies = vec![*boxed];
+    cookies.reserve(64);
+
+    assert_session(&cookies.pop().unwrap());
+}
+
+#[test]
+fn into_owned_outlives_original() {
+    let cookie = parse_owned();
+    let owned = cookie.clone().into_owned();
+
+    drop(cookie);
+
+    assert_session(&owned);
+}
+
+#[test]
+fn setters_replace_shared_attributes() {
+    let mut cookie = parse_owned();
+    let clone = cookie.clone();
+
+    cookie.set_value("def456");
+    cookie.set_path("/");
+
+    assert_eq!(cookie.value(), "def456");
+    assert_eq!(cookie.path(), Some("/"));
+    assert_session(&clone);
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn decoded_value_outlives_original() {
+    let cookie = Cookie::parse(String::from("name=hello%20world; Path=/")).unwrap();
+    let clone = cookie.clone();
+
+    drop(cookie);
+
+    assert_eq!(clone.value(), "hello world");
+    assert_eq!(clone.path(), Some("/"));
+}
+
+#[test]
+fn jar_cookie_outlives_jar() {
+    let jar = CookieJar::parse(String::from("session=abc123; theme=dark")).unwrap();
+    let session = jar.get("session").unwrap().clone();
+    let theme = jar.get("theme").cloned().map(Cookie::into_owned).unwrap();
+
+    drop(jar);
+
+    assert_eq!(session.to_string(), "session=abc123");
+    assert_eq!(theme.to_string(), "theme=dark");
+}
+
+#[test]
+fn jar_clone_outlives_original() {
+    let jar = CookieJar::parse(String::from("session=abc123; theme=dark")).unwrap();
+    let clone = jar.clone();
+
+    drop(jar);
+
+    assert_eq!(clone.get("session").unwrap().value(), "abc123");
+    assert_eq!(clone.get("theme").unwrap().value(), "dark");
+}
+
+#[test]
+fn borrowed_parse_borrows_input() {
+    let header = String::from("session=abc123");
+    let cookie = Cookie::parse(header.as_str()).unwrap();
+
+    assert_eq!(cookie.name().as_ptr(), header.as_ptr());
+}
@@ -99,6 +99,17 @@ fn cookie_with_samesite_lax() {
     assert_eq!(Cookie::parse(input), Ok(expected));
 }
 
+#[test]
+fn cookie_with_priority() {
+    let expected = Cookie::builder("name", "value")
+        .priority(Priority::High)
+        .build();
+    let input = "name=value; priority=high";
+
+    assert_eq!(Cookie::parse(input), Ok(expected.clone()));
+    assert_eq!(Cookie::parse_strict(input), Ok(expected));
+}
+
 #[test]
 fn cookie_with_unknown_attribute_strict() {
     let expected = Err(ParseError::UnknownAttribute("UnknownAttr".to_string()));
@@ -109,12 +120,51 @@ fn cookie_with_unknown_attribute_strict() {
 
 #[test]
 fn cookie_with_unknown_attribute_non_strict() {
-    let expected = Cookie::builder("name", "value").build();
+    let expected = Cookie::builder("name", "value")
+        .extension_flag("UnknownAttr")
+        .build();
     let input = "name=value; UnknownAttr";
 
     assert_eq!(Cookie::parse(input), Ok(expected));
 }
 
+#[test]
+fn cookie_with_extension_attributes() {
+    let input = "name=value; X-Custom=High; Path=/; SameParty; X-Vendor=a=b";
+    let cookie = Cookie::parse(input).unwrap();
+
+    assert_eq!(
+        cookie.extensions().collect::<Vec<_>>(),
+        [
+            ("X-Custom", Some("High")),
+            ("SameParty", None),
+            ("X-Vendor", Some("a=b"))
+        ]
+    );
+    assert_eq!(
+        cookie.to_string(),
+        "name=value; Path=/; X-Custom=High; SameParty; X-Vendor=a=b"
+    );
+    assert_eq!(Cookie::parse(cookie.to_string()), Ok(cookie));
+}
+
+#[test]
+fn cookie_set_extension_replaces_in_place() {
+    let mut cookie = Cookie::parse("name=value; X-Custom=Low; SameParty").unwrap();
+
+    cookie.set_extension("x-custom", "High");
+    cookie.set_extension_flag("Vendor");
+
+    assert_eq!(
+        cookie.to_string(),
+        "name=value; x-custom=High; SameParty; Vendor"
+    );
+
+    cookie.remove_extension("SAMEPARTY");
+
+    assert_eq!(cookie.to_string(), "name=value; x-custom=High; Vendor");
+}
+
 #[test]
 fn cookie_with_multiple_attributes() {
     let expected = Cookie::builder("name", "value")
@@ -140,13 +190,71 @@ fn malformed_cookie_missing_equals() {
 #[test]
 fn cookie_with_expires() {
     let expected = Cookie::builder("name", "value")
-        .expires("Wed, 21 Oct 2025 07:28:00 GMT")
+        .expires(Expiration::from_unix_timestamp(1761031680))
         .build();
     let input = "name=value; Expires=Wed, 21 Oct 2025 07:28:00 GMT";
 
     assert_eq!(Cookie::parse(input), Ok(expected));
 }
 
+#[test]
+fn cookie_with_expires_keeps_raw() {
+    let input = "name=value; Expires=wed, 21 OCT 2025 07:28:00 GMT";
+    let cookie = Cookie::parse(input).unwrap();
+
+    assert_eq!(cookie.expires_raw(), Some("wed, 21 OCT 2025 07:28:00 GMT"));
+    assert_eq!(
+        cookie.expires(),
+        Some(Expiration::from_unix_timestamp(1761031680))
+    );
+}
+
+#[test]
+fn cookie_with_invalid_expires_strict() {
+    let expected = Err(ParseError::ParseExpiresError(
+        ParseExpiresError::MissingTime,
+    ));
+    let input = "name=value; Expires=tomorrow";
+
+    assert_eq!(Cookie::parse_strict(input), expected);
+}
+
+#[test]
+fn cookie_with_invalid_expires_non_strict() {
+    let expected = Cookie::builder("name", "value").path("/").build();
+    let input = "name=value; Expires=tomorrow; Path=/";
+
+    assert_eq!(Cookie::parse(input), Ok(expected));
+}
+
+#[test]
+fn cookie_with_invalid_expires_non_strict_drops_raw() {
+    let cookie = Cookie::parse("name=value; Expires=Wed, 31 Feb 2025 07:28:00 GMT").unwrap();
+
+    assert_eq!(cookie.expires(), None);
+    assert_eq!(cookie.expires_raw(), None);
+}
+
+#[test]
+fn cookie_with_out_of_range_expires_strict() {
+    let expected = Err(ParseError::ParseExpiresError(
+        ParseExpiresError::InvalidDate(2025, 2, 31),
+    ));
+    let input = "name=value; Expires=Wed, 31 Feb 2025 07:28:00 GMT";
+
+    assert_eq!(Cookie::parse_strict(input), expected);
+}
+
+#[test]
+fn cookie_with_legacy_expires() {
+    let expected = Cookie::builder("name", "value")
+        .expires(Expiration::from_unix_timestamp(784111777))
+        .build();
+    let input = "name=value; Expires=Sunday, 06-Nov-94 08:49:37 GMT";
+
+    assert_eq!(Cookie::parse_strict(input), Ok(expected));
+}
+
 #[test]
 fn cookie_with_partitioned() {
     let expected = Cookie::builder("name", "value").partitioned(true).build();
@@ -254,6 +362,42 @@ fn cookie_with_invalid_samesite_value() {
     assert_eq!(Cookie::parse(input), expected);
 }
 
+#[test]
+fn cookie_with_invalid_priority_value() {
+    let expected = Cookie::builder("name", "value").secure(true).build();
+    let input = "name=value; Priority=Urgent; Secure";
+
+    assert_eq!(Cookie::parse(input), Ok(expected));
+}
+
+#[test]
+fn cookie_with_invalid_priority_value_strict() {
+    let expected = Err(ParseError::ParsePriorityError(
+        ParsePriorityError::UnknownValue("Urgent".to_string()),
+    ));
+    let input = "name=value; Priority=Urgent";
+
+    assert_eq!(Cookie::parse_strict(input), expected);
+}
+
+#[test]
+fn cookie_with_missing_priority_value() {
+    let expected = Err(ParseError::MissingPair(MissingPair::Priority));
+    let input = "name=value; Priority";
+
+    assert_eq!(Cookie::parse(input), Ok(Cookie::new("name", "value")));
+    assert_eq!(Cookie::parse_strict(input), expected);
+}
+
+#[test]
+fn cookie_with_priority_after_invalid_priority() {
+    let input = "name=value; Priority=Urgent; Priority=High";
+    let cookie = Cookie::parse(input).unwrap();
+
+    assert_eq!(cookie.priority(), Some(Priority::High));
+    assert_eq!(cookie.to_string(), "name=value; Priority=High");
+}
+
 #[test]
 fn cookie_with_trailing_semicolon() {
     let expected = Cookie::builder("name", "value").build();
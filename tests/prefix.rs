@@ -0,0 +1,122 @@
+use cookie_rs::cookie::prefix::Prefix;
+use cookie_rs::error::*;
+use cookie_rs::prelude::*;
+
+#[test]
+fn host_prefixed_builder() {
+    let cookie = CookieBuilder::host_prefixed("session", "abc").build();
+
+    assert_eq!(cookie.name(), "__Host-session");
+    assert_eq!(cookie.secure(), Some(true));
+    assert_eq!(cookie.path(), Some("/"));
+    assert_eq!(cookie.validate_prefix(), Ok(()));
+}
+
+#[test]
+fn secure_prefixed_builder() {
+    let cookie = CookieBuilder::secure_prefixed("id", "1").build();
+
+    assert_eq!(cookie.name(), "__Secure-id");
+    assert_eq!(cookie.secure(), Some(true));
+    assert_eq!(cookie.path(), None);
+    assert_eq!(cookie.validate_prefix(), Ok(()));
+}
+
+#[test]
+fn prefix_not_duplicated() {
+    let cookie = CookieBuilder::host_prefixed("__Host-session", "abc").build();
+
+    assert_eq!(cookie.name(), "__Host-session");
+}
+
+#[test]
+fn prefix_case_insensitive() {
+    assert_eq!(Prefix::of("__HOST-id"), Some(Prefix::Host));
+    assert_eq!(Prefix::of("__secure-id"), Some(Prefix::Secure));
+    assert_eq!(Prefix::of("__Hostid"), None);
+    assert_eq!(Prefix::of("_"), None);
+}
+
+#[test]
+fn unprefixed_always_valid() {
+    assert_eq!(Cookie::new("id", "1").validate_prefix(), Ok(()));
+}
+
+#[test]
+fn secure_prefix_requires_secure() {
+    let cookie = Cookie::new("__Secure-id", "1");
+
+    assert_eq!(
+        cookie.validate_prefix(),
+        Err(PrefixError::NotSecure(Prefix::Secure))
+    );
+}
+
+#[test]
+fn host_prefix_requires_root_path() {
+    let cookie = Cookie::builder("__Host-id", "1")
+        .secure(true)
+        .path("/app")
+        .build();
+
+    assert_eq!(cookie.validate_prefix(), Err(PrefixError::PathNotRoot));
+}
+
+#[test]
+fn host_prefix_rejects_domain() {
+    let cookie = CookieBuilder::host_prefixed("id", "1")
+        .domain("example.com")
+        .build();
+
+    assert_eq!(cookie.validate_prefix(), Err(PrefixError::HasDomain));
+}
+
+#[test]
+fn strict_parse_validates_prefix() {
+    let input = "__Host-id=1; Secure; Path=/; Domain=example.com";
+
+    assert_eq!(
+        Cookie::parse_strict(input),
+        Err(ParseError::PrefixError(PrefixError::HasDomain))
+    );
+    assert!(Cookie::parse(input).is_ok());
+    assert!(Cookie::parse_strict("__Host-id=1; Secure; Path=/").is_ok());
+}
+
+#[test]
+fn strict_jar_parse_ignores_prefix() {
+    let jar = CookieJar::parse_strict("__Host-id=1; __Secure-token=2").unwrap();
+
+    assert_eq!(jar.len(), 2);
+}
+
+#[test]
+fn jar_validates_before_emitting() {
+    let mut jar = CookieJar::default();
+    jar.add(CookieBuilder::secure_prefixed("id", "1").build());
+    jar.remove(CookieBuilder::host_prefixed("old", "").build());
+
+    assert_eq!(jar.try_as_header_values().unwrap().len(), 2);
+
+    jar.add(Cookie::new("__Secure-token", "2"));
+
+    assert_eq!(
+        jar.try_as_header_values(),
+        Err(ValidationError::PrefixError(PrefixError::NotSecure(
+            Prefix::Secure
+        )))
+    );
+}
+
+#[test]
+fn jar_validates_removals() {
+    let mut jar = CookieJar::default();
+    jar.remove("__Host-old");
+
+    assert_eq!(
+        jar.try_as_header_values(),
+        Err(ValidationError::PrefixError(PrefixError::NotSecure(
+            Prefix::Host
+        )))
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "private")]
+
+use cookie_rs::error::DecryptError;
+use cookie_rs::prelude::*;
+use cookie_rs::Key;
+
+fn key(byte: u8) -> Key {
+    Key::try_from(&[byte; 64][..]).unwrap()
+}
+
+#[test]
+fn private_jar_roundtrip() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+
+    jar.private_mut(&key).add(Cookie::new("user", "42; admin"));
+
+    let cookie = jar.private(&key).get("user").unwrap();
+    assert_eq!(cookie.value(), "42; admin");
+}
+
+#[test]
+fn private_jar_hides_value() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+
+    jar.private_mut(&key)
+        .add(Cookie::new("flags", "beta-dashboard"));
+
+    let headers = jar.as_header_values();
+    assert_eq!(headers.len(), 1);
+    assert!(headers[0].starts_with("flags="));
+    assert!(!headers[0].contains("beta-dashboard"));
+}
+
+#[test]
+fn private_jar_uses_fresh_nonce() {
+    let key = key(1);
+    let mut first = CookieJar::default();
+    let mut second = CookieJar::default();
+
+    first.private_mut(&key).add(Cookie::new("user", "42"));
+    second.private_mut(&key).add(Cookie::new("user", "42"));
+
+    assert_ne!(first.get("user"), second.get("user"));
+}
+
+#[test]
+fn private_jar_decrypts_parsed_header() {
+    let key = key(1);
+    let mut response = CookieJar::default();
+    response
+        .private_mut(&key)
+        .add(Cookie::builder("user", "42").path("/").build());
+
+    let header = response.as_header_values().remove(0);
+    let value = Cookie::parse(header).unwrap().value().to_owned();
+    let request = CookieJar::parse(format!("user={value}")).unwrap();
+
+    assert_eq!(request.private(&key).get("user").unwrap().value(), "42");
+}
+
+#[test]
+fn private_jar_binds_name() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+    jar.private_mut(&key).add(Cookie::new("user", "42"));
+
+    let value = jar.get("user").unwrap().value().to_owned();
+    jar.add(Cookie::new("admin", value));
+
+    assert_eq!(
+        jar.private(&key).try_get("admin"),
+        Err(DecryptError::Tampered)
+    );
+}
+
+#[test]
+fn private_jar_rejects_other_key() {
+    let mut jar = CookieJar::default();
+    jar.private_mut(&key(1)).add(Cookie::new("user", "42"));
+
+    assert!(jar.private(&key(2)).get("user").is_none());
+    assert_eq!(
+        jar.private(&key(2)).try_get("user"),
+        Err(DecryptError::Tampered)
+    );
+}
+
+#[test]
+fn private_jar_try_get_reports_errors() {
+    let key = key(1);
+    let jar = CookieJar::parse("a=not*base64; b=AAAA").unwrap();
+    let private = jar.private(&key);
+
+    assert_eq!(private.try_get("a"), Err(DecryptError::InvalidBase64));
+    assert_eq!(private.try_get("b"), Err(DecryptError::TooShort));
+    assert_eq!(private.try_get("c"), Err(DecryptError::Missing));
+}
+
+#[test]
+fn private_jar_remove() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+    jar.private_mut(&key).add(Cookie::new("user", "42"));
+    jar.private_mut(&key).remove("user");
+
+    assert_eq!(
+        jar.private(&key).try_get("user"),
+        Err(DecryptError::Missing)
+    );
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "psl")]
+
+use cookie_rs::error::*;
+use cookie_rs::prelude::*;
+use cookie_rs::psl::{self, PublicSuffixList};
+
+#[test]
+fn psl_bundled_icann_suffixes() {
+    assert!(psl::is_public_suffix("com"));
+    assert!(psl::is_public_suffix("co.uk"));
+    assert!(psl::is_public_suffix("CO.UK"));
+    assert!(!psl::is_public_suffix("example.co.uk"));
+}
+
+#[test]
+fn psl_bundled_private_suffixes() {
+    assert!(psl::is_public_suffix("github.io"));
+    assert!(!psl::is_public_suffix("magwoo.github.io"));
+}
+
+#[test]
+fn psl_wildcard_and_exception() {
+    let list = PublicSuffixList::bundled();
+
+    assert!(list.is_public_suffix("anything.ck"));
+    assert!(!list.is_public_suffix("www.ck"));
+    assert_eq!(list.public_suffix("a.www.ck"), "ck");
+    assert_eq!(list.public_suffix("a.b.ck"), "b.ck");
+}
+
+#[test]
+fn psl_internationalized_suffixes() {
+    let list = PublicSuffixList::bundled();
+
+    assert!(list.is_public_suffix("xn--55qx5d.cn"));
+    assert!(list.is_public_suffix("公司.cn"));
+    assert!(list.is_public_suffix("xn--fiqs8s"));
+    assert!(list.is_public_suffix("XN--55QX5D.XN--J6W193G"));
+    assert_eq!(list.public_suffix("shop.xn--55qx5d.cn"), "xn--55qx5d.cn");
+    assert!(!list.is_public_suffix("shop.xn--55qx5d.cn"));
+}
+
+#[test]
+fn psl_store_rejects_internationalized_public_suffix() {
+    let mut store = CookieStore::new();
+
+    assert_eq!(
+        store.insert("https://shop.xn--55qx5d.cn/", "a=1; Domain=xn--55qx5d.cn"),
+        Err(StoreError::PublicSuffix("xn--55qx5d.cn".to_string()))
+    );
+}
+
+#[test]
+fn psl_unlisted_tld_falls_back_to_last_label() {
+    let list = PublicSuffixList::bundled();
+
+    assert!(list.is_public_suffix("unknowntld"));
+    assert_eq!(list.public_suffix("example.unknowntld"), "unknowntld");
+}
+
+#[test]
+fn psl_empty_domain_is_not_public_suffix() {
+    assert!(!psl::is_public_suffix(""));
+}
+
+#[test]
+fn psl_from_file() {
+    let path = std::env::temp_dir().join(format!("cookie-rs-psl-{}.dat", std::process::id()));
+    std::fs::write(&path, "// local copy\nexample\n*.wild.example\n").unwrap();
+
+    let list = PublicSuffixList::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(list.is_public_suffix("example"));
+    assert!(list.is_public_suffix("any.wild.example"));
+    assert!(!list.is_public_suffix("site.example"));
+}
+
+#[test]
+fn psl_from_missing_file() {
+    assert!(PublicSuffixList::from_file("/nonexistent/public_suffix_list.dat").is_err());
+}
+
+#[test]
+fn psl_store_rejects_public_suffix_domain() {
+    let mut store = CookieStore::new();
+
+    assert_eq!(
+        store.insert("https://www.example.co.uk/", "a=1; Domain=co.uk"),
+        Err(StoreError::PublicSuffix("co.uk".to_string()))
+    );
+    assert!(store.is_empty());
+}
+
+#[test]
+fn psl_store_accepts_registrable_domain() {
+    let mut store = CookieStore::new();
+    store
+        .insert("https://www.example.co.uk/", "a=1; Domain=example.co.uk")
+        .unwrap();
+
+    assert!(store.cookie_header("https://example.co.uk/").is_some());
+}
+
+#[test]
+fn psl_store_public_suffix_host_becomes_host_only() {
+    let mut store = CookieStore::new();
+    store
+        .insert("https://github.io/", "a=1; Domain=github.io")
+        .unwrap();
+
+    assert!(store.cookie_header("https://github.io/").is_some());
+    assert!(store.cookie_header("https://magwoo.github.io/").is_none());
+}
+
+#[test]
+fn psl_store_custom_list() {
+    let mut store = CookieStore::new();
+    store.set_public_suffix_list(PublicSuffixList::parse("internal\n"));
+
+    assert!(store
+        .insert("https://a.internal/", "a=1; Domain=internal")
+        .is_err());
+    assert!(store
+        .insert("https://a.co.uk/", "a=1; Domain=co.uk")
+        .is_ok());
+}
+
+#[test]
+fn psl_validate_for_host() {
+    let cookie = Cookie::builder("a", "1").domain(".co.uk").build();
+
+    assert_eq!(
+        cookie.validate_for_host("example.co.uk"),
+        Err(StoreError::PublicSuffix("co.uk".to_string()))
+    );
+    assert!(Cookie::new("a", "1").validate_for_host("co.uk").is_ok());
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "reqwest")]
+
+use std::sync::Arc;
+
+use cookie_rs::store::SharedCookieStore;
+use cookie_rs::CookieStore;
+use reqwest::cookie::CookieStore as _;
+use reqwest::header::HeaderValue;
+use reqwest::Url;
+
+fn url(url: &str) -> Url {
+    Url::parse(url).unwrap()
+}
+
+#[test]
+fn set_and_get_cookies() {
+    let store = SharedCookieStore::default();
+    let headers = [
+        HeaderValue::from_static("session=abc123; Path=/; Secure"),
+        HeaderValue::from_static("theme=dark; Path=/docs"),
+    ];
+
+    store.set_cookies(&mut headers.iter(), &url("https://example.com/login"));
+
+    assert_eq!(
+        store.cookies(&url("https://example.com/docs/intro")),
+        Some(HeaderValue::from_static("theme=dark; session=abc123"))
+    );
+    assert_eq!(store.cookies(&url("http://example.com/")), None);
+}
+
+#[test]
+fn rejected_cookies_are_skipped() {
+    let store = SharedCookieStore::default();
+    let headers = [
+        HeaderValue::from_static("a=1; Domain=other.com"),
+        HeaderValue::from_static("=invalid"),
+        HeaderValue::from_bytes(b"b=\xff").unwrap(),
+        HeaderValue::from_static("c=3"),
+    ];
+
+    store.set_cookies(&mut headers.iter(), &url("https://example.com/"));
+
+    assert_eq!(store.read().len(), 1);
+    assert_eq!(
+        store.cookies(&url("https://example.com/")),
+        Some(HeaderValue::from_static("c=3"))
+    );
+}
+
+#[test]
+fn domain_cookies_shared_with_subdomains() {
+    let store = SharedCookieStore::default();
+    let headers = [HeaderValue::from_static("id=1; Domain=example.com")];
+
+    store.set_cookies(&mut headers.iter(), &url("https://www.example.com/"));
+
+    assert!(store.cookies(&url("https://api.example.com/")).is_some());
+    assert!(store.cookies(&url("https://example.org/")).is_none());
+}
+
+#[test]
+fn wraps_existing_store() {
+    let mut inner = CookieStore::new();
+    inner.insert("https://example.com/", "a=1").unwrap();
+
+    let store = SharedCookieStore::from(inner);
+
+    assert_eq!(
+        store.cookies(&url("https://example.com/")),
+        Some(HeaderValue::from_static("a=1"))
+    );
+    assert_eq!(store.into_inner().len(), 1);
+}
+
+#[test]
+fn shared_between_clients_and_threads() {
+    let store = Arc::new(SharedCookieStore::default());
+
+    let _a = reqwest::Client::builder()
+        .cookie_provider(Arc::clone(&store))
+        .build()
+        .unwrap();
+    let _b = reqwest::Client::builder()
+        .cookie_provider(Arc::clone(&store))
+        .build()
+        .unwrap();
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let store = Arc::clone(&store);
+
+            std::thread::spawn(move || {
+                let header = HeaderValue::try_from(format!("c{i}={i}")).unwrap();
+                store.set_cookies(&mut [header].iter(), &url("https://example.com/"));
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(store.read().len(), 4);
+}
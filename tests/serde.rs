@@ -0,0 +1,150 @@
+#![cfg(feature = "serde")]
+
+use std::time::Duration;
+
+use cookie_rs::jar::CookieChange;
+use cookie_rs::prelude::*;
+
+#[test]
+fn serialize_cookie() {
+    let cookie = Cookie::builder("session", "abc123")
+        .domain("example.com")
+        .path("/")
+        .max_age(Duration::from_secs(3600))
+        .expires(Expiration::from_unix_timestamp(1761031680))
+        .same_site(SameSite::Lax)
+        .secure(true)
+        .build();
+
+    assert_eq!(
+        serde_json::to_string(&cookie).unwrap(),
+        r#"{"name":"session","value":"abc123","domain":"example.com","expires":1761031680,"max_age":3600,"path":"/","same_site":"Lax","secure":true}"#
+    );
+}
+
+#[test]
+fn serialize_minimal_cookie() {
+    let cookie = Cookie::new("a", "1");
+
+    assert_eq!(
+        serde_json::to_string(&cookie).unwrap(),
+        r#"{"name":"a","value":"1"}"#
+    );
+}
+
+#[test]
+fn cookie_roundtrip() {
+    let cookie = Cookie::builder("session", "a b;c")
+        .domain("example.com")
+        .path("/app")
+        .http_only(true)
+        .partitioned(true)
+        .max_age(Duration::from_secs(60))
+        .expires(Expiration::from_unix_timestamp(0))
+        .same_site(SameSite::None)
+        .secure(true)
+        .priority(Priority::High)
+        .extension("X-Custom", "1")
+        .extension_flag("SameParty")
+        .build();
+
+    let json = serde_json::to_string(&cookie).unwrap();
+    let parsed: Cookie = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed, cookie);
+}
+
+fn borrows_from(value: &str, input: &str) -> bool {
+    input.as_bytes().as_ptr_range().contains(&value.as_ptr())
+}
+
+#[test]
+fn deserialize_borrows() {
+    let json = r#"{"name":"session","value":"abc123","path":"/","extensions":[["Vendor","1"],["SameParty",null]]}"#;
+    let cookie: Cookie = serde_json::from_str(json).unwrap();
+
+    assert!(borrows_from(cookie.name(), json));
+    assert!(borrows_from(cookie.value(), json));
+    assert!(borrows_from(cookie.path().unwrap(), json));
+
+    let extensions = cookie.extensions().collect::<Vec<_>>();
+
+    assert_eq!(extensions, [("Vendor", Some("1")), ("SameParty", None)]);
+    assert!(borrows_from(extensions[0].0, json));
+    assert!(borrows_from(extensions[0].1.unwrap(), json));
+    assert!(borrows_from(extensions[1].0, json));
+}
+
+#[test]
+fn deserialize_escaped_is_owned() {
+    let json = r#"{"name":"a","value":"x\ny"}"#;
+    let cookie: Cookie = serde_json::from_str(json).unwrap();
+
+    assert_eq!(cookie.value(), "x\ny");
+    assert!(!borrows_from(cookie.value(), json));
+}
+
+#[test]
+fn deserialize_missing_value() {
+    assert!(serde_json::from_str::<Cookie>(r#"{"name":"a"}"#).is_err());
+}
+
+#[test]
+fn same_site() {
+    assert_eq!(
+        serde_json::to_string(&SameSite::Strict).unwrap(),
+        r#""Strict""#
+    );
+    assert_eq!(
+        serde_json::from_str::<SameSite>(r#""None""#).unwrap(),
+        SameSite::None
+    );
+}
+
+#[test]
+fn cookie_change() {
+    let change = CookieChange::create(Cookie::new("a", "1"));
+
+    let json = serde_json::to_string(&change).unwrap();
+    assert_eq!(json, r#"{"Create":{"name":"a","value":"1"}}"#);
+
+    let parsed: CookieChange = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.cookie(), change.cookie());
+}
+
+#[test]
+fn jar_roundtrip() {
+    let mut jar = CookieJar::parse("session=abc123; user=bob").unwrap();
+    jar.add(Cookie::builder("theme", "dark").path("/").build());
+    jar.remove("user");
+
+    let json = serde_json::to_string(&jar).unwrap();
+    let parsed: CookieJar = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.get("session").unwrap().value(), "abc123");
+    assert_eq!(parsed.get("theme").unwrap().path(), Some("/"));
+    assert!(parsed.get("user").is_none());
+    assert_eq!(parsed.as_header_values(), jar.as_header_values());
+}
+
+#[test]
+fn jar_default_fields() {
+    let jar: CookieJar = serde_json::from_str("{}").unwrap();
+
+    assert!(jar.is_empty());
+    assert!(jar.changes().is_empty());
+}
+
+#[test]
+fn nested_cookie_borrows() {
+    #[derive(serde::Deserialize)]
+    struct Session<'a> {
+        #[serde(borrow)]
+        cookie: Cookie<'a>,
+    }
+
+    let json = r#"{"cookie":{"name":"a","value":"1"}}"#;
+    let session: Session = serde_json::from_str(json).unwrap();
+
+    assert!(borrows_from(session.cookie.value(), json));
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "signed")]
+
+use cookie_rs::prelude::*;
+use cookie_rs::Key;
+
+fn key(byte: u8) -> Key {
+    Key::try_from(&[byte; 64][..]).unwrap()
+}
+
+#[test]
+fn signed_jar_roundtrip() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+
+    jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+
+    let cookie = jar.signed(&key).get("session").unwrap();
+    assert_eq!(cookie.value(), "abc123");
+}
+
+#[test]
+fn signed_jar_value_carries_mac() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+
+    jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+
+    let raw = jar.get("session").unwrap().value();
+    assert_eq!(raw.len(), 44 + "abc123".len());
+    assert!(raw.ends_with("abc123"));
+}
+
+#[test]
+fn signed_jar_additions_in_header_values() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+
+    jar.signed_mut(&key)
+        .add(Cookie::builder("session", "abc123").path("/").build());
+
+    let headers = jar.as_header_values();
+    assert_eq!(headers.len(), 1);
+    assert!(headers[0].starts_with("session="));
+    assert!(headers[0].ends_with("abc123; Path=/"));
+}
+
+#[test]
+fn signed_jar_verifies_parsed_header() {
+    let key = key(1);
+    let mut response = CookieJar::default();
+    response
+        .signed_mut(&key)
+        .add(Cookie::new("session", "abc123"));
+
+    let header = response.as_header_values().remove(0);
+    let request = CookieJar::parse(header).unwrap();
+
+    assert_eq!(
+        request.signed(&key).get("session").unwrap().value(),
+        "abc123"
+    );
+}
+
+#[test]
+fn signed_jar_rejects_tampered_value() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+    jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+
+    let tampered = jar
+        .get("session")
+        .unwrap()
+        .value()
+        .replace("abc123", "admin1");
+    jar.add(Cookie::new("session", tampered));
+
+    assert!(jar.signed(&key).get("session").is_none());
+}
+
+#[test]
+fn signed_jar_rejects_other_key() {
+    let mut jar = CookieJar::default();
+    jar.signed_mut(&key(1))
+        .add(Cookie::new("session", "abc123"));
+
+    assert!(jar.signed(&key(2)).get("session").is_none());
+}
+
+#[test]
+fn signed_jar_rejects_renamed_cookie() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+    jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+
+    let value = jar.get("session").unwrap().value().to_owned();
+    jar.add(Cookie::new("admin", value));
+
+    assert!(jar.signed(&key).get("admin").is_none());
+}
+
+#[test]
+fn signed_jar_rejects_unsigned_and_short_values() {
+    let key = key(1);
+    let jar = CookieJar::parse("a=plain; b=").unwrap();
+
+    assert!(jar.signed(&key).get("a").is_none());
+    assert!(jar.signed(&key).get("b").is_none());
+    assert!(jar.signed(&key).get("c").is_none());
+}
+
+#[test]
+fn signed_jar_remove() {
+    let key = key(1);
+    let mut jar = CookieJar::default();
+    jar.signed_mut(&key).add(Cookie::new("session", "abc123"));
+    jar.signed_mut(&key).remove("session");
+
+    assert!(jar.signed(&key).get("session").is_none());
+}
+
+#[test]
+fn key_too_short() {
+    assert!(Key::try_from(&[0; 63][..]).is_err());
+}
@@ -0,0 +1,193 @@
+use cookie_rs::error::*;
+use cookie_rs::prelude::*;
+
+fn spanned(input: &str) -> (SpannedParseError, &str) {
+    let err = Cookie::parse_spanned(input).unwrap_err();
+    let token = &input[err.span().range()];
+
+    (err, token)
+}
+
+fn spanned_strict(input: &str) -> (SpannedParseError, &str) {
+    let err = Cookie::parse_strict_spanned(input).unwrap_err();
+    let token = &input[err.span().range()];
+
+    (err, token)
+}
+
+#[test]
+fn spanned_error_matches_parse() {
+    for input in [
+        "name",
+        "=value",
+        "name=value; Max-Age=soon",
+        "name=value; Domain",
+        "name=value; SameSite=Sometimes",
+        "name=value; Priority=Urgent",
+    ] {
+        assert_eq!(
+            Cookie::parse_spanned(input).map_err(SpannedParseError::into_error),
+            Cookie::parse(input)
+        );
+        assert_eq!(
+            Cookie::parse_strict_spanned(input).map_err(Into::into),
+            Cookie::parse_strict(input)
+        );
+    }
+}
+
+#[test]
+fn name_value_spans() {
+    let (err, token) = spanned("  session  ; Path=/");
+    assert_eq!(
+        err.error(),
+        &ParseError::MissingPair(MissingPair::NameValue)
+    );
+    assert_eq!(token, "session");
+    assert_eq!(err.span().attribute(), 0);
+
+    let (err, token) = spanned(" =abc; Path=/");
+    assert_eq!(err.error(), &ParseError::EmptyName);
+    assert_eq!(token, "=abc");
+}
+
+#[test]
+fn attribute_value_spans() {
+    let (err, token) = spanned("name=value; Path=/; Max-Age = soon ; Secure");
+    assert!(matches!(err.error(), ParseError::ParseMaxAgeError(_)));
+    assert_eq!(token, "soon");
+    assert_eq!(err.span().attribute(), 2);
+
+    let (err, token) = spanned("name=value; SameSite=Sometimes");
+    assert_eq!(token, "Sometimes");
+    assert_eq!(err.span().range(), 21..30);
+}
+
+#[test]
+fn missing_attribute_value_spans() {
+    let (err, token) = spanned("name=value; Secure; Domain");
+    assert_eq!(err.error(), &ParseError::MissingPair(MissingPair::Domain));
+    assert_eq!(token, "Domain");
+    assert_eq!(err.span().attribute(), 2);
+}
+
+#[test]
+fn strict_spans() {
+    let (err, token) = spanned_strict("name=value; Secure; Foo=bar");
+    assert_eq!(
+        err.error(),
+        &ParseError::UnknownAttribute("Foo".to_string())
+    );
+    assert_eq!(token, "Foo");
+    assert_eq!(err.span().attribute(), 2);
+
+    let (err, token) = spanned_strict("name=value; Expires=someday");
+    assert!(matches!(err.error(), ParseError::ParseExpiresError(_)));
+    assert_eq!(token, "someday");
+
+    let (err, token) = spanned_strict("bad name=value");
+    assert_eq!(
+        err.error(),
+        &ParseError::ValidationError(ValidationError::InvalidName(' '))
+    );
+    assert_eq!(token, "bad name");
+
+    let (err, token) = spanned_strict("name=value; Path=/a\tb");
+    assert_eq!(
+        err.error(),
+        &ParseError::ValidationError(ValidationError::InvalidPath('\t'))
+    );
+    assert_eq!(token, "/a\tb");
+
+    let (err, token) = spanned_strict("__Host-id=1; Path=/");
+    assert!(matches!(err.error(), ParseError::PrefixError(_)));
+    assert_eq!(token, "__Host-id");
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn decode_error_span() {
+    let (err, token) = spanned("name=%FF%FE; Path=/");
+    assert_eq!(err.error(), &ParseError::ParseDecodeError);
+    assert_eq!(token, "%FF%FE");
+}
+
+#[test]
+fn owned_input_spans() {
+    let input = "name=value; Max-Age=soon";
+    let err = Cookie::parse_spanned(input.to_string()).unwrap_err();
+
+    assert_eq!(&input[err.span().range()], "soon");
+}
+
+#[test]
+fn jar_spans() {
+    let header = "a=1;; b=2;  broken ; c=3";
+    let err = CookieJar::parse_spanned(header).unwrap_err();
+
+    assert_eq!(&header[err.span().range()], "broken");
+    assert_eq!(err.span().attribute(), 3);
+    assert_eq!(CookieJar::parse(header).unwrap_err(), err.into_error());
+
+    let header = "a=1; b c=2";
+    let err = CookieJar::parse_strict_spanned(header.to_string()).unwrap_err();
+
+    assert_eq!(&header[err.span().range()], "b c");
+    assert_eq!(err.span().attribute(), 1);
+}
+
+#[test]
+fn render_short_input() {
+    let input = "name=value; Max-Age=soon";
+    let (err, _) = spanned(input);
+
+    assert_eq!(
+        err.render(input),
+        "error: failed to parse Max-Age: invalid digit found in string\n \
+         --> attribute 1, bytes 20..24\n  \
+           |\n  \
+           | name=value; Max-Age=soon\n  \
+           |                     ^^^^\n"
+    );
+}
+
+#[test]
+fn render_long_input_is_windowed() {
+    let input = format!(
+        "name={}; SameSite=Sometimes; Path={}",
+        "v".repeat(2000),
+        "/p".repeat(100)
+    );
+    let (err, _) = spanned(&input);
+    let rendered = err.render(&input);
+    let lines = rendered.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 5);
+    assert!(lines[3].starts_with("  | ...vvv"));
+    assert!(lines[3].ends_with("..."));
+
+    let caret = lines[4].find('^').unwrap();
+    assert_eq!(&lines[3][caret..caret + 9], "Sometimes");
+    assert_eq!(lines[4].matches('^').count(), 9);
+}
+
+#[test]
+fn render_replaces_control_characters() {
+    let input = "name=value;\tPath=/a\rb";
+    let (err, _) = spanned_strict(input);
+    let rendered = err.render(input);
+
+    assert!(!rendered.contains('\r'));
+    assert!(!rendered.contains('\t'));
+    assert!(rendered.ends_with("|                  ^^^^\n"));
+}
+
+#[test]
+fn display() {
+    let (err, _) = spanned("name=value; Domain");
+
+    assert_eq!(
+        err.to_string(),
+        "missed pair: Domain (attribute 1, bytes 12..18)"
+    );
+}
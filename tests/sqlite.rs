@@ -0,0 +1,159 @@
+#![cfg(feature = "sqlite")]
+
+use std::path::PathBuf;
+
+use cookie_rs::error::SqliteError;
+use cookie_rs::prelude::*;
+use rusqlite::Connection;
+
+const FIREFOX_SCHEMA: &str = "CREATE TABLE moz_cookies (
+    id INTEGER PRIMARY KEY, originAttributes TEXT NOT NULL DEFAULT '',
+    name TEXT, value TEXT, host TEXT, path TEXT, expiry INTEGER,
+    lastAccessed INTEGER, creationTime INTEGER, isSecure INTEGER,
+    isHttpOnly INTEGER, inBrowserElement INTEGER DEFAULT 0,
+    sameSite INTEGER DEFAULT 0, rawSameSite INTEGER DEFAULT 0,
+    schemeMap INTEGER DEFAULT 0)";
+
+const CHROMIUM_SCHEMA: &str = "CREATE TABLE cookies (
+    creation_utc INTEGER NOT NULL, host_key TEXT NOT NULL,
+    top_frame_site_key TEXT NOT NULL DEFAULT '', name TEXT NOT NULL,
+    value TEXT NOT NULL, encrypted_value BLOB NOT NULL DEFAULT x'',
+    path TEXT NOT NULL, expires_utc INTEGER NOT NULL, is_secure INTEGER NOT NULL,
+    is_httponly INTEGER NOT NULL, last_access_utc INTEGER NOT NULL DEFAULT 0,
+    has_expires INTEGER NOT NULL DEFAULT 1, is_persistent INTEGER NOT NULL DEFAULT 1,
+    priority INTEGER NOT NULL DEFAULT 1, samesite INTEGER NOT NULL DEFAULT -1,
+    source_scheme INTEGER NOT NULL DEFAULT 0)";
+
+/// Creates a fresh database in the temp directory and returns its path.
+fn fixture(name: &str, sql: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("cookie-rs-{}-{name}.sqlite", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    Connection::open(&path).unwrap().execute_batch(sql).unwrap();
+
+    path
+}
+
+#[test]
+fn firefox_cookies() {
+    let path = fixture(
+        "firefox",
+        &format!(
+            "{FIREFOX_SCHEMA};
+            INSERT INTO moz_cookies (name, value, host, path, expiry, creationTime, isSecure, isHttpOnly, sameSite)
+            VALUES ('theme', 'dark', 'www.example.com', '/docs', 1761031680, 2, 0, 0, 1),
+                   ('session', 'abc123', '.example.com', '/', 32503680000, 1, 1, 1, 2),
+                   ('note', 'a%20b', 'example.com', '/notes', 0, 3, 0, 0, 0);",
+        ),
+    );
+
+    let store = CookieStore::from_firefox(&path).unwrap();
+    let cookies = store.iter().collect::<Vec<_>>();
+
+    assert_eq!(cookies.len(), 3);
+
+    assert_eq!(cookies[0].name(), "session");
+    assert_eq!(cookies[0].domain(), Some("example.com"));
+    assert_eq!(cookies[0].secure(), Some(true));
+    assert_eq!(cookies[0].http_only(), Some(true));
+    assert_eq!(cookies[0].same_site(), Some(SameSite::Strict));
+
+    assert_eq!(cookies[1].name(), "theme");
+    assert_eq!(cookies[1].domain(), None);
+    assert_eq!(cookies[1].path(), Some("/docs"));
+    assert_eq!(cookies[1].secure(), None);
+    assert_eq!(cookies[1].same_site(), Some(SameSite::Lax));
+    assert_eq!(cookies[1].expires().unwrap().unix_timestamp(), 1761031680);
+
+    #[cfg(feature = "percent-encoding")]
+    assert_eq!(cookies[2].value(), "a b");
+    #[cfg(not(feature = "percent-encoding"))]
+    assert_eq!(cookies[2].value(), "a%20b");
+
+    assert_eq!(
+        store.cookie_header("https://www.example.com/").as_deref(),
+        Some("session=abc123")
+    );
+    assert_eq!(
+        store.cookie_header("https://example.com/docs").as_deref(),
+        Some("session=abc123")
+    );
+    assert_eq!(
+        store.cookie_header("https://example.com/notes").as_deref(),
+        Some("note=a%20b; session=abc123")
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn firefox_invalid_same_site() {
+    let path = fixture(
+        "firefox-same-site",
+        &format!(
+            "{FIREFOX_SCHEMA};
+            INSERT INTO moz_cookies (name, value, host, path, expiry, creationTime, isSecure, isHttpOnly, sameSite)
+            VALUES ('a', '1', 'example.com', '/', 0, 1, 0, 0, 7);",
+        ),
+    );
+
+    assert!(matches!(
+        CookieStore::from_firefox(&path),
+        Err(SqliteError::InvalidSameSite(7))
+    ));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn chromium_cookies() {
+    // 13405505280000000 µs since 1601 is 2025-10-21T07:28:00Z
+    let path = fixture(
+        "chromium",
+        &format!(
+            "{CHROMIUM_SCHEMA};
+            INSERT INTO cookies (creation_utc, host_key, name, value, path, expires_utc, is_secure, is_httponly, samesite, priority)
+            VALUES (1, '.example.com', 'session', 'abc123', '/', 13405505280000000, 1, 1, 0, 2);
+            INSERT INTO cookies (creation_utc, host_key, name, value, path, expires_utc, is_secure, is_httponly, samesite)
+            VALUES
+                   (2, 'example.com', 'theme', 'dark', '/', 0, 0, 0, -1),
+                   (3, 'example.com', 'lang', 'en', '/', 0, 0, 0, 1);
+            INSERT INTO cookies (creation_utc, host_key, name, value, encrypted_value, path, expires_utc, is_secure, is_httponly)
+            VALUES (4, 'example.com', 'secret', '', x'763130', '/', 0, 0, 0);",
+        ),
+    );
+
+    let store = CookieStore::from_chromium(&path).unwrap();
+    let cookies = store.iter().collect::<Vec<_>>();
+
+    assert_eq!(cookies.len(), 3);
+
+    assert_eq!(cookies[0].name(), "session");
+    assert_eq!(cookies[0].domain(), Some("example.com"));
+    assert_eq!(cookies[0].http_only(), Some(true));
+    assert_eq!(cookies[0].same_site(), Some(SameSite::None));
+    assert_eq!(cookies[0].expires().unwrap().unix_timestamp(), 1761031680);
+    assert_eq!(cookies[0].priority(), Some(Priority::High));
+
+    assert_eq!(cookies[1].name(), "theme");
+    assert_eq!(cookies[1].domain(), None);
+    assert_eq!(cookies[1].expires(), None);
+    assert_eq!(cookies[1].same_site(), None);
+    assert_eq!(cookies[1].priority(), Some(Priority::Medium));
+
+    assert_eq!(cookies[2].same_site(), Some(SameSite::Lax));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn missing_table() {
+    let path = fixture("empty", "CREATE TABLE other (id INTEGER)");
+
+    assert!(matches!(
+        CookieStore::from_chromium(&path),
+        Err(SqliteError::Sqlite(_))
+    ));
+
+    std::fs::remove_file(path).unwrap();
+}
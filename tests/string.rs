@@ -72,6 +72,18 @@ fn cookie_with_samesite_strict() {
     assert_eq!(input.to_string(), expected);
 }
 
+#[test]
+fn cookie_with_priority() {
+    let expected = "name=value; Path=/; Priority=High; SameSite=Lax";
+    let input = Cookie::builder("name", "value")
+        .same_site(SameSite::Lax)
+        .priority(Priority::High)
+        .path("/")
+        .build();
+
+    assert_eq!(input.to_string(), expected);
+}
+
 #[test]
 fn cookie_with_all_attributes() {
     let expected =
@@ -122,14 +134,22 @@ fn cookie_with_partitioned() {
 
 #[test]
 fn cookie_with_expires() {
-    let expected = "name=value; Expires=Wed, 21 Oct 2025 07:28:00 GMT";
+    let expected = "name=value; Expires=Tue, 21 Oct 2025 07:28:00 GMT";
     let input = Cookie::builder("name", "value")
-        .expires("Wed, 21 Oct 2025 07:28:00 GMT")
+        .expires(Expiration::from_unix_timestamp(1761031680))
         .build();
 
     assert_eq!(input.to_string(), expected);
 }
 
+#[test]
+fn cookie_with_parsed_expires_is_normalized() {
+    let expected = "name=value; Expires=Tue, 21 Oct 2025 07:28:00 GMT";
+    let input = Cookie::parse("name=value; expires=wed, 21 oct 2025 07:28:00 GMT").unwrap();
+
+    assert_eq!(input.to_string(), expected);
+}
+
 #[cfg(feature = "percent-encoding")]
 #[test]
 fn cookie_value_encoded_in_display() {
@@ -159,3 +179,22 @@ fn cookie_safe_value_not_encoded() {
     let cookie = Cookie::new("data", "hello-world_123");
     assert_eq!(cookie.to_string(), "data=hello-world_123");
 }
+
+#[test]
+fn cookie_extension_with_known_name() {
+    let mut cookie = Cookie::builder("name", "value")
+        .priority(Priority::Low)
+        .build();
+
+    cookie.set_extension("Priority", "High");
+    cookie.set_extension("priority", "Urgent");
+    cookie.set_extension("PATH", "/app");
+    cookie.set_extension_flag("secure");
+    cookie.set_extension_flag("Domain");
+
+    assert_eq!(cookie.extensions().count(), 0);
+    assert_eq!(
+        cookie.to_string(),
+        "name=value; Path=/app; Priority=High; Secure"
+    );
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "tower")]
+
+use std::convert::Infallible;
+
+use axum::body::Body;
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::{Request, Response, StatusCode};
+use axum::routing::get;
+use axum::{Extension, Router};
+use cookie_rs::error::{MissingPair, ParseError, SetCookieError, ValidationError};
+use cookie_rs::jar::{CookieLayer, SharedJar};
+use cookie_rs::prelude::*;
+use tower::{service_fn, Layer, ServiceExt};
+
+fn set_cookies<B>(response: &Response<B>) -> Vec<String> {
+    response
+        .headers()
+        .get_all(SET_COOKIE)
+        .iter()
+        .map(|v| v.to_str().unwrap().to_owned())
+        .collect()
+}
+
+#[tokio::test]
+async fn jar_in_extensions() {
+    let service = CookieLayer::new().layer(service_fn(|req: Request<()>| async move {
+        let jar = req.extensions().get::<SharedJar>().unwrap();
+        let user = jar.lock().get("user").unwrap().value().to_owned();
+
+        Ok::<_, Infallible>(Response::new(user))
+    }));
+
+    let request = Request::get("/")
+        .header(COOKIE, "user=bob")
+        .body(())
+        .unwrap();
+    let response = service.oneshot(request).await.unwrap();
+
+    assert_eq!(response.body(), "bob");
+    assert!(set_cookies(&response).is_empty());
+}
+
+#[tokio::test]
+async fn changes_written_to_response() {
+    let service = CookieLayer::new().layer(service_fn(|req: Request<()>| async move {
+        let jar = req.extensions().get::<SharedJar>().unwrap().clone();
+        jar.lock().add(Cookie::new("session", "abc123"));
+        jar.lock().remove("user");
+
+        let mut response = Response::new(());
+        response
+            .headers_mut()
+            .append(SET_COOKIE, "other=1".parse().unwrap());
+
+        Ok::<_, Infallible>(response)
+    }));
+
+    let request = Request::get("/")
+        .header(COOKIE, "user=bob")
+        .body(())
+        .unwrap();
+    let response = service.oneshot(request).await.unwrap();
+    let headers = set_cookies(&response);
+
+    assert_eq!(headers.len(), 3);
+    assert_eq!(headers[0], "other=1");
+    assert!(headers.contains(&"session=abc123".to_owned()));
+    assert!(headers.iter().any(|h| h.starts_with("user=;")));
+}
+
+#[tokio::test]
+async fn malformed_pairs_are_skipped() {
+    let service = CookieLayer::new().layer(service_fn(|req: Request<()>| async move {
+        let jar = req.extensions().get::<SharedJar>().unwrap();
+        let error = req.extensions().get::<ParseError>().cloned();
+
+        Ok::<_, Infallible>(Response::new((jar.lock().len(), error)))
+    }));
+
+    let request = Request::get("/")
+        .header(COOKIE, "a=1; novalue; b=2")
+        .body(())
+        .unwrap();
+    let response = service.oneshot(request).await.unwrap();
+
+    assert_eq!(
+        *response.body(),
+        (2, Some(ParseError::MissingPair(MissingPair::NameValue)))
+    );
+}
+
+#[tokio::test]
+async fn invalid_change_fails_response() {
+    let service = CookieLayer::new().layer(service_fn(|req: Request<()>| async move {
+        let jar = req.extensions().get::<SharedJar>().unwrap();
+        jar.lock().add(Cookie::new("theme", "dark"));
+        jar.lock()
+            .add(Cookie::builder("session", "abc123").path("/a\x01").build());
+
+        Ok::<_, Infallible>(Response::new("ok".to_owned()))
+    }));
+
+    let request = Request::get("/").body(()).unwrap();
+    let response = service.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response.body().is_empty());
+    assert!(set_cookies(&response).is_empty());
+    assert_eq!(
+        response.extensions().get::<SetCookieError>(),
+        Some(&SetCookieError::ValidationError(
+            ValidationError::InvalidPath('\u{1}')
+        ))
+    );
+}
+
+#[tokio::test]
+async fn with_axum_router() {
+    async fn handler(Extension(jar): Extension<SharedJar>) -> String {
+        let mut jar = jar.lock();
+        let visits = jar
+            .get("visits")
+            .and_then(|c| c.value().parse::<u32>().ok())
+            .unwrap_or(0);
+
+        jar.add(Cookie::new("visits", (visits + 1).to_string()));
+
+        visits.to_string()
+    }
+
+    let app = Router::new()
+        .route("/", get(handler))
+        .layer(CookieLayer::new());
+
+    let request = Request::get("/")
+        .header(COOKIE, "visits=2")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(set_cookies(&response), ["visits=3"]);
+}
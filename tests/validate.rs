@@ -0,0 +1,202 @@
+use cookie_rs::error::*;
+use cookie_rs::prelude::*;
+
+#[test]
+fn valid_cookie() {
+    let cookie = Cookie::builder("session_id", "abc123")
+        .domain("example.com")
+        .path("/app")
+        .build();
+
+    assert_eq!(cookie.validate(), Ok(()));
+}
+
+#[test]
+fn empty_name() {
+    assert_eq!(
+        Cookie::new("", "v").validate(),
+        Err(ValidationError::EmptyName)
+    );
+}
+
+#[test]
+fn name_separators() {
+    for c in [
+        '(', ')', '<', '>', '@', ',', ';', ':', '\\', '"', '/', '[', ']', '?', '=', '{', '}', ' ',
+        '\t',
+    ] {
+        let name = format!("na{c}me");
+
+        assert_eq!(
+            Cookie::new(name, "v").validate(),
+            Err(ValidationError::InvalidName(c))
+        );
+    }
+}
+
+#[test]
+fn name_control_and_non_ascii() {
+    assert_eq!(
+        Cookie::new("a\r\nb", "v").validate(),
+        Err(ValidationError::InvalidName('\r'))
+    );
+    assert_eq!(
+        Cookie::new("naïve", "v").validate(),
+        Err(ValidationError::InvalidName('ï'))
+    );
+}
+
+#[test]
+fn name_token_chars() {
+    assert_eq!(Cookie::new("!#$%&'*+-.^_`|~09AZaz", "v").validate(), Ok(()));
+}
+
+#[test]
+#[cfg(not(feature = "percent-encoding"))]
+fn value_cookie_octets() {
+    assert_eq!(
+        Cookie::new("n", "a;b").validate(),
+        Err(ValidationError::InvalidValue(';'))
+    );
+    assert_eq!(
+        Cookie::new("n", "a b").validate(),
+        Err(ValidationError::InvalidValue(' '))
+    );
+    assert_eq!(
+        Cookie::new("n", "a\r\n").validate(),
+        Err(ValidationError::InvalidValue('\r'))
+    );
+    assert_eq!(Cookie::new("n", "\"quoted\"").validate(), Ok(()));
+    assert_eq!(
+        Cookie::new("n", "\"a\"b\"").validate(),
+        Err(ValidationError::InvalidValue('"'))
+    );
+}
+
+#[test]
+#[cfg(feature = "percent-encoding")]
+fn value_encoded() {
+    let cookie = Cookie::new("n", "a; b\r\n");
+
+    assert_eq!(cookie.validate(), Ok(()));
+    assert_eq!(cookie.to_string(), "n=a%3B%20b%0D%0A");
+}
+
+#[test]
+fn attribute_injection() {
+    let cookie = Cookie::builder("n", "v")
+        .domain("example.com; Secure")
+        .build();
+    assert_eq!(cookie.validate(), Err(ValidationError::InvalidDomain(';')));
+
+    let cookie = Cookie::builder("n", "v").path("/\nSet-Cookie: x=y").build();
+    assert_eq!(cookie.validate(), Err(ValidationError::InvalidPath('\n')));
+}
+
+#[test]
+fn extension_octets() {
+    let cookie = Cookie::builder("n", "v")
+        .extension("Priority", "High")
+        .build();
+    assert_eq!(cookie.validate(), Ok(()));
+
+    let cookie = Cookie::builder("n", "v").extension("X", "a\r\nb").build();
+    assert_eq!(
+        cookie.validate(),
+        Err(ValidationError::InvalidExtension('\r'))
+    );
+
+    let cookie = Cookie::builder("n", "v").extension_flag("a=b").build();
+    assert_eq!(
+        cookie.validate(),
+        Err(ValidationError::InvalidExtension('='))
+    );
+
+    let cookie = Cookie::builder("n", "v").extension_flag("a;b").build();
+    assert_eq!(
+        cookie.validate(),
+        Err(ValidationError::InvalidExtension(';'))
+    );
+}
+
+#[test]
+fn prefix_is_validated() {
+    assert_eq!(
+        Cookie::new("__Host-id", "1").validate(),
+        Err(ValidationError::PrefixError(PrefixError::NotSecure(
+            cookie_rs::cookie::prefix::Prefix::Host
+        )))
+    );
+}
+
+#[test]
+fn try_new() {
+    assert!(Cookie::try_new("session", "abc").is_ok());
+    assert_eq!(
+        Cookie::try_new("se=ssion", "abc"),
+        Err(ValidationError::InvalidName('='))
+    );
+}
+
+#[test]
+fn builder_try_new() {
+    let cookie = CookieBuilder::try_new("__Secure-id", "abc")
+        .unwrap()
+        .secure(true)
+        .build();
+    assert_eq!(cookie.to_string(), "__Secure-id=abc; Secure");
+
+    assert!(matches!(
+        CookieBuilder::try_new("", "abc"),
+        Err(ValidationError::EmptyName)
+    ));
+    assert!(matches!(
+        CookieBuilder::try_new("se;ssion", "abc"),
+        Err(ValidationError::InvalidName(';'))
+    ));
+}
+
+#[cfg(not(feature = "percent-encoding"))]
+#[test]
+fn builder_try_new_checks_value() {
+    assert!(matches!(
+        CookieBuilder::try_new("session", "a;b"),
+        Err(ValidationError::InvalidValue(';'))
+    ));
+}
+
+#[test]
+fn try_build() {
+    assert!(Cookie::builder("session", "abc")
+        .path("/")
+        .try_build()
+        .is_ok());
+    assert_eq!(
+        Cookie::builder("session", "abc").path("/;").try_build(),
+        Err(ValidationError::InvalidPath(';'))
+    );
+}
+
+#[test]
+fn strict_parse_rejects_invalid_name() {
+    assert_eq!(
+        Cookie::parse_strict("a b=c"),
+        Err(ParseError::ValidationError(ValidationError::InvalidName(
+            ' '
+        )))
+    );
+    assert!(Cookie::parse("a b=c").is_ok());
+    assert!(CookieJar::parse_strict("ok=1; a@b=2").is_err());
+    assert!(CookieJar::parse("ok=1; a@b=2").is_ok());
+}
+
+#[test]
+fn jar_validates_removals() {
+    let mut jar = CookieJar::default();
+    jar.remove("bad\r\nname");
+
+    assert_eq!(
+        jar.try_as_header_values(),
+        Err(ValidationError::InvalidName('\r'))
+    );
+}